@@ -1,54 +1,91 @@
 use std::fmt::Debug;
-use std::sync::{LazyLock, Mutex};
+use std::sync::Once;
+
+use backtrace::Backtrace;
 
 use crate::context::AssertionContext;
+use crate::error::{AssertionError, AssertionFailure};
+use crate::panic::locate_assertion;
 
-static ASSERTION_LOCK: LazyLock<Mutex<()>> = LazyLock::new(|| Mutex::new(()));
+static INSTALL_HOOK: Once = Once::new();
 
-pub(crate) fn assert_unary<T: Debug>(
+pub(crate) fn check_unary<T: Debug>(
     actual: T,
     evaluator: impl FnOnce(T) -> bool,
     original_actual: T,
     context: impl Into<AssertionContext<T>>,
-    generate_message: impl FnOnce(T, AssertionContext<T>) -> String,
-) {
+    generate_message: impl FnOnce(T, AssertionContext<T>) -> AssertionError,
+) -> Result<(), AssertionError> {
     if evaluator(actual) {
-        return;
+        return Ok(());
     }
 
-    let message = generate_message(original_actual, context.into());
-
-    register_hook_and_panic(&message);
+    Err(generate_message(original_actual, context.into()))
 }
 
-pub(crate) fn assert_comparison<T: Debug, O: Debug>(
+pub(crate) fn check_comparison<T: Debug, O: Debug>(
     actual: T,
     evaluator: impl FnOnce(T) -> bool,
     original_actual: T,
     expected: O,
     context: impl Into<AssertionContext<T>>,
-    generate_message: impl FnOnce(T, O, AssertionContext<T>) -> String,
-) {
+    generate_message: impl FnOnce(T, O, AssertionContext<T>) -> AssertionError,
+) -> Result<(), AssertionError> {
     if evaluator(actual) {
-        return;
+        return Ok(());
+    }
+
+    Err(generate_message(original_actual, expected, context.into()))
+}
+
+/// Raise the failure from one of the `check_*` functions, if any.
+pub(crate) fn assert_result(result: Result<(), AssertionError>) {
+    if let Err(error) = result {
+        register_hook_and_panic(error);
+    }
+}
+
+/// Return the value checked by one of the `try_*` functions, raising its
+/// failure otherwise. As there is no value to continue with, such failures
+/// panic even inside a soft scope.
+pub(crate) fn unwrap_result<V>(result: Result<V, AssertionError>) -> V {
+    match result {
+        Ok(value) => value,
+        Err(error) => raise(locate_failure(error)),
     }
+}
 
-    let message = generate_message(original_actual, expected, context.into());
+fn register_hook_and_panic(error: AssertionError) {
+    let failure = locate_failure(error);
 
-    register_hook_and_panic(&message);
+    // Inside a soft scope, failures are collected rather than raised.
+    if crate::soft::record(&failure) {
+        return;
+    }
+
+    raise(failure);
 }
 
-fn register_hook_and_panic(message: &str) {
-    // Super basic / slow synchronization across threads
-    let _guard = ASSERTION_LOCK.lock().expect("lock poisoned - bail");
+fn locate_failure(error: AssertionError) -> AssertionFailure {
+    match locate_assertion(&Backtrace::new()) {
+        Some(site) => AssertionFailure::new(error, site.location, site.code_snippet.ok()),
+        None => AssertionFailure::new(error, None, None),
+    }
+}
 
-    // Grab the default hook to handle "outside" panics.
-    let default_hook = std::panic::take_hook();
+fn raise(failure: AssertionFailure) -> ! {
+    install_hook();
+    std::panic::panic_any(failure);
+}
 
-    // Set the panic hook to this crate's hook, with the default hook as backup.
-    std::panic::set_hook(Box::new(move |info| {
-        crate::panic::test_hook(info, &default_hook)
-    }));
+/// Install this crate's hook exactly once, keeping the previous hook as a
+/// backup to handle "outside" panics.
+pub(crate) fn install_hook() {
+    INSTALL_HOOK.call_once(|| {
+        let default_hook = std::panic::take_hook();
 
-    panic!("{message}");
+        std::panic::set_hook(Box::new(move |info| {
+            crate::panic::test_hook(info, &default_hook)
+        }));
+    });
 }
@@ -1,7 +1,6 @@
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::path::Path;
-use std::usize::MAX;
 
 use regex::Regex;
 
@@ -12,37 +11,36 @@ struct CodeLocator<'a> {
 }
 
 impl<'a> CodeLocator<'a> {
-    pub fn new(message: &'a str) -> Self {
+    pub fn new(message: &'a str) -> Result<Self, String> {
         let matcher = Regex::new(r"(?<fqp>.*?):(?<line>\d+):(?<column>\d+)").unwrap();
-        let captures = matcher.captures(message).unwrap();
+        let captures = matcher
+            .captures(message)
+            .ok_or(format!("could not parse location: '{message}'"))?;
 
-        Self {
+        Ok(Self {
             fqp: Path::new(captures.name("fqp").unwrap().as_str()),
             line_number: captures.name("line").unwrap().as_str().parse().unwrap(),
             column: captures.name("column").unwrap().as_str().parse().unwrap(),
-        }
+        })
     }
 }
 
-pub fn get_assertion_function<'a>(message: &str) -> String {
-    let index = message.rfind("::").unwrap();
-    message[index + 2..].to_string()
+pub fn get_assertion_function(message: &str) -> String {
+    match message.rfind("::") {
+        Some(index) => message[index + 2..].to_string(),
+        None => message.to_string(),
+    }
 }
 
-pub fn get_code_snippet(message: &str, assertion_fn: &str) -> String {
-    let locator = CodeLocator::new(message);
-
-    match inner(locator, assertion_fn) {
-        Ok(s) => s,
-        Err(e) => format!("(could not get source: {e:?})"),
-    }
+pub fn get_code_snippet(message: &str, assertion_fn: &str) -> Result<String, String> {
+    CodeLocator::new(message).and_then(|locator| inner(locator, assertion_fn))
 }
 
 fn inner(locator: CodeLocator, assertion_fn: &str) -> Result<String, String> {
     let handle = File::open(locator.fqp).map_err(|x| x.to_string())?;
     let reader = BufReader::new(handle);
 
-    let line = reader.lines().skip(locator.line_number - 1).next();
+    let line = reader.lines().nth(locator.line_number.saturating_sub(1));
 
     match line {
         Some(line) => {
@@ -50,11 +48,53 @@ fn inner(locator: CodeLocator, assertion_fn: &str) -> Result<String, String> {
             if line.len() <= locator.column {
                 return Err(format!("line does not have a column {}", locator.column));
             }
-            let pos = line.rfind(assertion_fn).ok_or(format!(
+            let pos = line.rfind(&format!(".{assertion_fn}")).ok_or(format!(
                 "line does not have assertion function: '{assertion_fn}'"
             ))?;
-            Ok(line[locator.column - 1..pos - 1].to_string())
+
+            // Depending on the compiler, the reported column either points at
+            // the start of the receiver or at the method name itself.
+            let start = match locator.column.checked_sub(1) {
+                Some(column) if column < pos => column,
+                _ => receiver_start(&line[..pos]),
+            };
+            Ok(line[start..pos].to_string())
         }
         None => Err(format!("could not access line {}", locator.line_number)),
     }
 }
+
+/// Walks backwards from the end of 'code' over a method-call receiver (paths,
+/// field accesses, calls, indexing and literals), returning the byte offset at
+/// which the receiver starts.
+fn receiver_start(code: &str) -> usize {
+    let bytes = code.as_bytes();
+    let mut start = bytes.len();
+    let mut depth = 0usize;
+    let mut in_string = false;
+
+    while start > 0 {
+        let byte = bytes[start - 1];
+
+        if in_string {
+            if byte == b'"' && (start < 2 || bytes[start - 2] != b'\\') {
+                in_string = false;
+            }
+        } else {
+            match byte {
+                b'"' => in_string = true,
+                b')' | b']' | b'}' => depth += 1,
+                b'(' | b'[' | b'{' if depth > 0 => depth -= 1,
+                b'(' | b'[' | b'{' => break,
+                _ if depth > 0 => {}
+                b'.' | b':' | b'&' | b'_' | b'!' => {}
+                _ if byte.is_ascii_alphanumeric() => {}
+                _ => break,
+            }
+        }
+
+        start -= 1;
+    }
+
+    start
+}
@@ -0,0 +1,15 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static MAX_REPORTED_ELEMENTS: AtomicUsize = AtomicUsize::new(10);
+
+/// Set how many offending elements a failed assertion over a sequence lists
+/// individually before summarizing the rest. Defaults to 10.
+///
+/// This applies to every thread of the process.
+pub fn set_max_reported_elements(limit: usize) {
+    MAX_REPORTED_ELEMENTS.store(limit, Ordering::Relaxed);
+}
+
+pub(crate) fn max_reported_elements() -> usize {
+    MAX_REPORTED_ELEMENTS.load(Ordering::Relaxed)
+}
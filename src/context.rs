@@ -1,7 +1,6 @@
 use std::fmt::Debug;
 
 pub struct AssertionContext<T: Debug> {
-    pub asserted_expression: String,
     pub verb: String,
     pub actual_mapper: Box<dyn FnOnce(T) -> String>,
     pub custom_message: Option<String>,
@@ -10,8 +9,6 @@ pub struct AssertionContext<T: Debug> {
 impl<T: Debug> Default for AssertionContext<T> {
     fn default() -> Self {
         Self {
-            // TODO: should evaluate the backtrace by default
-            asserted_expression: "Placeholder code".to_string(),
             verb: "should be".to_string(),
             actual_mapper: Box::new(|x| format!(" {x:?}")),
             custom_message: None,
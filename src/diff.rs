@@ -0,0 +1,704 @@
+use std::fmt::Debug;
+use std::io::IsTerminal;
+
+/// A single step of an edit script turning an 'expected' sequence into an
+/// 'actual' one. Indices refer to positions within the respective sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Edit {
+    Equal { expected: usize, actual: usize },
+    Removed { expected: usize },
+    Inserted { actual: usize },
+}
+
+// Beyond this many cells, the LCS table is too costly to build, so the
+// differing middle section is reported as a wholesale replacement.
+const MAX_LCS_CELLS: usize = 4_000_000;
+
+/// Compute an edit script between 'expected' and 'actual' based on their
+/// longest common subsequence.
+pub(crate) fn edit_script<T: PartialEq>(expected: &[T], actual: &[T]) -> Vec<Edit> {
+    let prefix = expected
+        .iter()
+        .zip(actual)
+        .take_while(|(x, y)| x == y)
+        .count();
+    let suffix = expected[prefix..]
+        .iter()
+        .rev()
+        .zip(actual[prefix..].iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+
+    let expected_middle = &expected[prefix..expected.len() - suffix];
+    let actual_middle = &actual[prefix..actual.len() - suffix];
+
+    let mut edits: Vec<Edit> = (0..prefix)
+        .map(|i| Edit::Equal {
+            expected: i,
+            actual: i,
+        })
+        .collect();
+
+    let middle = if expected_middle.len() * actual_middle.len() > MAX_LCS_CELLS {
+        replacement(expected_middle.len(), actual_middle.len())
+    } else {
+        lcs(expected_middle, actual_middle)
+    };
+
+    edits.extend(middle.into_iter().map(|edit| match edit {
+        Edit::Equal { expected, actual } => Edit::Equal {
+            expected: expected + prefix,
+            actual: actual + prefix,
+        },
+        Edit::Removed { expected } => Edit::Removed {
+            expected: expected + prefix,
+        },
+        Edit::Inserted { actual } => Edit::Inserted {
+            actual: actual + prefix,
+        },
+    }));
+
+    edits.extend((0..suffix).map(|i| Edit::Equal {
+        expected: expected.len() - suffix + i,
+        actual: actual.len() - suffix + i,
+    }));
+
+    edits
+}
+
+fn replacement(expected_len: usize, actual_len: usize) -> Vec<Edit> {
+    (0..expected_len)
+        .map(|expected| Edit::Removed { expected })
+        .chain((0..actual_len).map(|actual| Edit::Inserted { actual }))
+        .collect()
+}
+
+fn lcs<T: PartialEq>(expected: &[T], actual: &[T]) -> Vec<Edit> {
+    let (n, m) = (expected.len(), actual.len());
+
+    // lengths[i][j] is the LCS length of expected[i..] and actual[j..].
+    let mut lengths = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lengths[i][j] = if expected[i] == actual[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut edits = Vec::with_capacity(n + m);
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if expected[i] == actual[j] {
+            edits.push(Edit::Equal {
+                expected: i,
+                actual: j,
+            });
+            i += 1;
+            j += 1;
+        } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+            edits.push(Edit::Removed { expected: i });
+            i += 1;
+        } else {
+            edits.push(Edit::Inserted { actual: j });
+            j += 1;
+        }
+    }
+    edits.extend((i..n).map(|expected| Edit::Removed { expected }));
+    edits.extend((j..m).map(|actual| Edit::Inserted { actual }));
+
+    edits
+}
+
+/// Whether failures printed to stderr should have their diffs colored.
+/// Honors the `NO_COLOR` convention, see https://no-color.org.
+pub(crate) fn use_color() -> bool {
+    let disabled = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+    !disabled && std::io::stderr().is_terminal()
+}
+
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const RESET: &str = "\x1b[0m";
+
+const DIFF_HEADER: &str = "Diff (-expected +actual):";
+
+/// Render a line-based diff of two texts, marking lines only in 'expected'
+/// with '-' and lines only in 'actual' with '+'.
+pub(crate) fn line_diff(expected: &str, actual: &str) -> String {
+    let expected_lines = diffable_lines(expected);
+    let actual_lines = diffable_lines(actual);
+
+    let mut rendered = String::from(DIFF_HEADER);
+    for edit in edit_script(&expected_lines, &actual_lines) {
+        let (marker, line) = match edit {
+            Edit::Equal { expected, .. } => (' ', expected_lines[expected]),
+            Edit::Removed { expected } => ('-', expected_lines[expected]),
+            Edit::Inserted { actual } => ('+', actual_lines[actual]),
+        };
+        rendered.push_str(&format!("\n{marker} {line}"));
+    }
+
+    rendered
+}
+
+/// Color the diffs within a failure 'message' for printing to a terminal:
+/// removed lines red and inserted lines green, with the characters pointed
+/// out on a following '?' line highlighted instead. Diffs are stored
+/// uncolored, so that failures compare the same wherever they are produced.
+pub(crate) fn colorize(message: &str) -> String {
+    let lines: Vec<&str> = message.split('\n').collect();
+    let mut colored = Vec::with_capacity(lines.len());
+    let mut in_diff = false;
+
+    let mut index = 0;
+    while index < lines.len() {
+        let line = lines[index];
+        index += 1;
+
+        if !in_diff || line.is_empty() {
+            // A diff runs from its header up to the next blank line.
+            in_diff = line == DIFF_HEADER;
+            colored.push(line.to_string());
+            continue;
+        }
+
+        let paint = match line.as_bytes().first() {
+            Some(b'-') => RED,
+            Some(b'+') => GREEN,
+            _ => {
+                colored.push(line.to_string());
+                continue;
+            }
+        };
+        let carets = lines.get(index).and_then(|next| next.strip_prefix("? "));
+        if carets.is_some() {
+            index += 1;
+        }
+        colored.push(paint_line(line, carets.unwrap_or_default(), paint));
+    }
+
+    colored.join("\n")
+}
+
+/// Paint a changed 'line', highlighting the characters of its content which
+/// have a '^' below them in 'carets'.
+fn paint_line(line: &str, carets: &str, paint: &str) -> String {
+    let (marker, content) = line.split_at(line.len().min(2));
+    let mut carets = carets.chars();
+    let mut highlighted = false;
+
+    let mut painted = format!("{paint}{marker}");
+    for c in content.chars() {
+        let changed = carets.next() == Some('^');
+        if changed != highlighted {
+            painted.push_str(if changed { HIGHLIGHT } else { UNHIGHLIGHT });
+            highlighted = changed;
+        }
+        painted.push(c);
+    }
+    if highlighted {
+        painted.push_str(UNHIGHLIGHT);
+    }
+    painted.push_str(RESET);
+
+    painted
+}
+
+/// How many unchanged lines 'text_diff' shows around each change.
+const CONTEXT_LINES: usize = 3;
+
+const HIGHLIGHT: &str = "\x1b[7m";
+const UNHIGHLIGHT: &str = "\x1b[27m";
+
+/// Render a unified line diff of two texts, for comparing strings directly
+/// rather than their 'Debug' representations.
+///
+/// Invisible characters are shown explicitly, long unchanged regions are cut
+/// down to a few lines of context, and within each changed line the differing
+/// characters are pointed out on a '?' line below it.
+pub(crate) fn text_diff(expected: &str, actual: &str) -> String {
+    let expected_lines: Vec<&str> = expected.split('\n').collect();
+    let actual_lines: Vec<&str> = actual.split('\n').collect();
+    let edits = edit_script(&expected_lines, &actual_lines);
+
+    let mut rendered = String::from(DIFF_HEADER);
+    let mut start = 0;
+    while start < edits.len() {
+        let unchanged = matches!(edits[start], Edit::Equal { .. });
+        let end = edits[start..]
+            .iter()
+            .position(|edit| matches!(edit, Edit::Equal { .. }) != unchanged)
+            .map_or(edits.len(), |length| start + length);
+
+        if unchanged {
+            let lines: Vec<&str> = edits[start..end]
+                .iter()
+                .filter_map(|edit| match edit {
+                    Edit::Equal { expected, .. } => Some(expected_lines[*expected]),
+                    _ => None,
+                })
+                .collect();
+            render_unchanged(&mut rendered, &lines, start > 0, end < edits.len());
+        } else {
+            let removed: Vec<&str> = edits[start..end]
+                .iter()
+                .filter_map(|edit| match edit {
+                    Edit::Removed { expected } => Some(expected_lines[*expected]),
+                    _ => None,
+                })
+                .collect();
+            let inserted: Vec<&str> = edits[start..end]
+                .iter()
+                .filter_map(|edit| match edit {
+                    Edit::Inserted { actual } => Some(actual_lines[*actual]),
+                    _ => None,
+                })
+                .collect();
+            render_changes(&mut rendered, &removed, &inserted);
+        }
+
+        start = end;
+    }
+
+    rendered
+}
+
+/// Render a run of unchanged lines, keeping only the context next to the
+/// changes 'before' and 'after' it.
+fn render_unchanged(rendered: &mut String, lines: &[&str], before: bool, after: bool) {
+    let leading = if before { CONTEXT_LINES } else { 0 };
+    let trailing = if after { CONTEXT_LINES } else { 0 };
+
+    // Only cut the run when that hides more than the marker line it adds.
+    if lines.len() <= leading + trailing + 1 {
+        for line in lines {
+            rendered.push_str(&format!("\n  {}", visible(line).concat()));
+        }
+        return;
+    }
+
+    for line in &lines[..leading] {
+        rendered.push_str(&format!("\n  {}", visible(line).concat()));
+    }
+    rendered.push_str(&format!(
+        "\n  ... {} unchanged lines ...",
+        lines.len() - leading - trailing
+    ));
+    for line in &lines[lines.len() - trailing..] {
+        rendered.push_str(&format!("\n  {}", visible(line).concat()));
+    }
+}
+
+/// Render a run of changed lines, highlighting the differing characters of
+/// each 'removed' line paired with a similar 'inserted' line.
+fn render_changes(rendered: &mut String, removed: &[&str], inserted: &[&str]) {
+    let mut removed_marks = vec![None; removed.len()];
+    let mut inserted_marks = vec![None; inserted.len()];
+
+    for (index, (expected, actual)) in removed.iter().zip(inserted).enumerate() {
+        if let Some((expected_marks, actual_marks)) = changed_chars(expected, actual) {
+            removed_marks[index] = Some(expected_marks);
+            inserted_marks[index] = Some(actual_marks);
+        }
+    }
+
+    for (line, marks) in removed.iter().zip(&removed_marks) {
+        render_changed_line(rendered, '-', line, marks.as_deref());
+    }
+    for (line, marks) in inserted.iter().zip(&inserted_marks) {
+        render_changed_line(rendered, '+', line, marks.as_deref());
+    }
+}
+
+fn render_changed_line(rendered: &mut String, marker: char, line: &str, marks: Option<&[bool]>) {
+    let pieces = visible(line);
+
+    rendered.push_str(&format!("\n{marker} {}", pieces.concat()));
+    // A line which only lost or gained characters has none to point at.
+    if let Some(marks) = marks.filter(|marks| marks.contains(&true)) {
+        let underline: String = pieces
+            .iter()
+            .zip(marks)
+            .map(|(piece, &changed)| {
+                let width = piece.chars().count();
+                if changed { "^" } else { " " }.repeat(width)
+            })
+            .collect();
+        rendered.push_str(&format!("\n? {}", underline.trim_end()));
+    }
+}
+
+/// Which characters of 'expected' and 'actual' differ, or 'None' if the two
+/// lines have too little in common for highlighting to help.
+fn changed_chars(expected: &str, actual: &str) -> Option<(Vec<bool>, Vec<bool>)> {
+    let expected: Vec<char> = expected.chars().collect();
+    let actual: Vec<char> = actual.chars().collect();
+
+    let mut expected_marks = vec![false; expected.len()];
+    let mut actual_marks = vec![false; actual.len()];
+    let mut common = 0;
+    for edit in edit_script(&expected, &actual) {
+        match edit {
+            Edit::Equal { .. } => common += 1,
+            Edit::Removed { expected } => expected_marks[expected] = true,
+            Edit::Inserted { actual } => actual_marks[actual] = true,
+        }
+    }
+
+    (common * 2 >= expected.len().max(actual.len())).then_some((expected_marks, actual_marks))
+}
+
+/// Render each character of 'line', making invisible ones explicit: tabs as
+/// '→', carriage returns as '␍', trailing spaces as '·' and other control
+/// characters as escapes.
+fn visible(line: &str) -> Vec<String> {
+    let content_end = line.trim_end().chars().count();
+
+    line.chars()
+        .enumerate()
+        .map(|(index, c)| match c {
+            '\t' => "→".to_string(),
+            '\r' => "␍".to_string(),
+            ' ' if index >= content_end => "·".to_string(),
+            c if c.is_control() => c.escape_debug().to_string(),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+/// Whether the 'Debug' representations are worth diffing, i.e. whether at
+/// least one of them spans several lines.
+pub(crate) fn is_multiline(expected: &str, actual: &str) -> bool {
+    diffable_lines(expected).len() > 1 || diffable_lines(actual).len() > 1
+}
+
+// A string's 'Debug' representation is a single quoted line with escaped
+// newlines, so split those at the escapes to diff them line by line.
+fn diffable_lines(text: &str) -> Vec<&str> {
+    let is_string_literal =
+        text.len() >= 2 && text.starts_with('"') && text.ends_with('"') && !text.contains('\n');
+
+    if !is_string_literal {
+        return text.lines().collect();
+    }
+
+    let bytes = text.as_bytes();
+    let mut lines = Vec::new();
+    let mut start = 0;
+    let mut index = 0;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'\\' if bytes.get(index + 1) == Some(&b'n') => {
+                lines.push(&text[start..index]);
+                index += 2;
+                start = index;
+            }
+            // Skip over the escaped character, so that an escaped backslash
+            // followed by an 'n' is not taken for a newline.
+            b'\\' => index += 2,
+            _ => index += 1,
+        }
+    }
+    lines.push(&text[start..]);
+
+    lines
+}
+
+/// Render where two sequences diverge: the first mismatching index with the
+/// element pair found there, followed by every change needed to turn
+/// 'expected' into 'actual'.
+pub(crate) fn sequence_diff<T: PartialEq + Debug>(expected: &[T], actual: &[T]) -> String {
+    let mut rendered = match first_mismatch(expected, actual) {
+        Some(index) => {
+            let expected_item = describe_item(expected.get(index));
+            let actual_item = describe_item(actual.get(index));
+            format!(
+                "First mismatch at index {index}: expected {expected_item} but was {actual_item}"
+            )
+        }
+        None => return "Sequences are equal".to_string(),
+    };
+
+    if let Some(length_mismatch) = describe_length_mismatch(expected, actual) {
+        rendered.push('\n');
+        rendered.push_str(&length_mismatch);
+    }
+
+    rendered.push_str("\n\nEdits to turn expected into actual:");
+
+    let edits = edit_script(expected, actual);
+    let mut position = 0;
+    while position < edits.len() {
+        // Pair each run of removals with the insertions directly following
+        // it, reporting those as changed elements.
+        let removed: Vec<usize> = edits[position..]
+            .iter()
+            .map_while(|edit| match edit {
+                Edit::Removed { expected } => Some(*expected),
+                _ => None,
+            })
+            .collect();
+        let inserted: Vec<usize> = edits[position + removed.len()..]
+            .iter()
+            .map_while(|edit| match edit {
+                Edit::Inserted { actual } => Some(*actual),
+                _ => None,
+            })
+            .collect();
+
+        if removed.is_empty() && inserted.is_empty() {
+            position += 1;
+            continue;
+        }
+        position += removed.len() + inserted.len();
+
+        let changed = removed.len().min(inserted.len());
+        for (&i, &j) in removed.iter().zip(&inserted) {
+            rendered.push_str(&format!(
+                "\n    changed expected[{i}] {:?} to actual[{j}] {:?}",
+                expected[i], actual[j]
+            ));
+        }
+        for &i in &removed[changed..] {
+            rendered.push_str(&format!("\n    removed expected[{i}] {:?}", expected[i]));
+        }
+        for &j in &inserted[changed..] {
+            rendered.push_str(&format!("\n    inserted actual[{j}] {:?}", actual[j]));
+        }
+    }
+
+    rendered
+}
+
+fn describe_length_mismatch<T: PartialEq + Debug>(expected: &[T], actual: &[T]) -> Option<String> {
+    let plural = |count: usize| if count == 1 { "" } else { "s" };
+
+    if actual.len() > expected.len() {
+        let extra = actual.len() - expected.len();
+        if actual.starts_with(expected) {
+            Some(format!(
+                "Actual had {extra} extra element{}: {:?}",
+                plural(extra),
+                &actual[expected.len()..]
+            ))
+        } else {
+            Some(format!("Actual had {extra} extra element{}", plural(extra)))
+        }
+    } else if actual.len() < expected.len() {
+        let missing = expected.len() - actual.len();
+        if expected.starts_with(actual) {
+            Some(format!(
+                "Actual was missing trailing elements {:?}",
+                &expected[actual.len()..]
+            ))
+        } else {
+            Some(format!(
+                "Actual was missing {missing} element{}",
+                plural(missing)
+            ))
+        }
+    } else {
+        None
+    }
+}
+
+fn first_mismatch<T: PartialEq>(expected: &[T], actual: &[T]) -> Option<usize> {
+    let common = expected
+        .iter()
+        .zip(actual)
+        .take_while(|(x, y)| x == y)
+        .count();
+
+    if common == expected.len() && common == actual.len() {
+        None
+    } else {
+        Some(common)
+    }
+}
+
+fn describe_item<T: Debug>(item: Option<&T>) -> String {
+    match item {
+        Some(item) => format!("{item:?}"),
+        None => "nothing (sequence ended)".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edit_script_finds_common_subsequence() {
+        let edits = edit_script(&['a', 'b', 'c', 'd'], &['a', 'c', 'x', 'd']);
+
+        assert_eq!(
+            edits,
+            vec![
+                Edit::Equal {
+                    expected: 0,
+                    actual: 0
+                },
+                Edit::Removed { expected: 1 },
+                Edit::Equal {
+                    expected: 2,
+                    actual: 1
+                },
+                Edit::Inserted { actual: 2 },
+                Edit::Equal {
+                    expected: 3,
+                    actual: 3
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn edit_script_handles_empty_sides() {
+        assert_eq!(
+            edit_script::<i32>(&[], &[1]),
+            vec![Edit::Inserted { actual: 0 }]
+        );
+        assert_eq!(
+            edit_script::<i32>(&[1], &[]),
+            vec![Edit::Removed { expected: 0 }]
+        );
+        assert!(edit_script::<i32>(&[], &[]).is_empty());
+    }
+
+    #[test]
+    fn line_diff_marks_changed_lines() {
+        let expected = "Object {\n    name: \"a\",\n    id: 1,\n}";
+        let actual = "Object {\n    name: \"b\",\n    id: 1,\n}";
+
+        assert_eq!(
+            line_diff(expected, actual),
+            "Diff (-expected +actual):\n  Object {\n-     name: \"a\",\n+     name: \"b\",\n      id: 1,\n  }"
+        );
+    }
+
+    #[test]
+    fn colorize_colors_only_diff_lines() {
+        let message = format!(" should be 1 but was 2\n\n{}", line_diff("-a", "b"));
+
+        assert_eq!(
+            colorize(&message),
+            format!(
+                " should be 1 but was 2\n\nDiff (-expected +actual):\n{RED}- -a{RESET}\n{GREEN}+ b{RESET}"
+            )
+        );
+        assert_eq!(colorize("- not a diff"), "- not a diff");
+    }
+
+    #[test]
+    fn string_literals_are_split_on_escaped_newlines() {
+        assert!(is_multiline("\"one\\ntwo\"", "\"one\""));
+        assert!(!is_multiline("\"one\"", "\"two\""));
+
+        assert_eq!(
+            line_diff("\"one\\ntwo\"", "\"one\\nthree\""),
+            "Diff (-expected +actual):\n  \"one\n- two\"\n+ three\""
+        );
+
+        // An escaped backslash followed by an 'n' is not a newline.
+        assert!(!is_multiline(&format!("{:?}", "a\\nb"), "\"a\""));
+    }
+
+    #[test]
+    fn text_diff_highlights_changed_characters() {
+        assert_eq!(
+            text_diff("let x = 1;\nok", "let x = 2;\nok"),
+            "Diff (-expected +actual):\n\
+             - let x = 1;\n\
+             ?         ^\n\
+             + let x = 2;\n\
+             ?         ^\n  \
+             ok"
+        );
+
+        let colored = colorize(&text_diff("ab", "ac"));
+        assert_eq!(
+            colored,
+            format!(
+                "Diff (-expected +actual):\n\
+                 {RED}- a{HIGHLIGHT}b{UNHIGHLIGHT}{RESET}\n\
+                 {GREEN}+ a{HIGHLIGHT}c{UNHIGHLIGHT}{RESET}"
+            )
+        );
+    }
+
+    #[test]
+    fn text_diff_shows_invisible_characters() {
+        assert_eq!(
+            text_diff("a\tb \nc", "a\tb\r\nc"),
+            "Diff (-expected +actual):\n\
+             - a→b·\n\
+             ?    ^\n\
+             + a→b␍\n\
+             ?    ^\n  \
+             c"
+        );
+    }
+
+    #[test]
+    fn text_diff_truncates_unchanged_regions() {
+        let expected: Vec<String> = (0..20).map(|i| format!("line {i}")).collect();
+        let mut actual = expected.clone();
+        actual[10] = "changed".to_string();
+
+        assert_eq!(
+            text_diff(&expected.join("\n"), &actual.join("\n")),
+            "Diff (-expected +actual):\n  \
+             ... 7 unchanged lines ...\n  \
+             line 7\n  \
+             line 8\n  \
+             line 9\n\
+             - line 10\n\
+             + changed\n  \
+             line 11\n  \
+             line 12\n  \
+             line 13\n  \
+             ... 6 unchanged lines ..."
+        );
+    }
+
+    #[test]
+    fn sequence_diff_reports_first_mismatch_and_edits() {
+        assert_eq!(
+            sequence_diff(&[1, 2, 3, 4], &[1, 5, 3, 6, 7]),
+            "First mismatch at index 1: expected 2 but was 5\n\
+             Actual had 1 extra element\n\n\
+             Edits to turn expected into actual:\n    \
+             changed expected[1] 2 to actual[1] 5\n    \
+             changed expected[3] 4 to actual[3] 6\n    \
+             inserted actual[4] 7"
+        );
+
+        assert_eq!(
+            sequence_diff(&[1, 2], &[2]),
+            "First mismatch at index 0: expected 1 but was 2\n\
+             Actual was missing 1 element\n\n\
+             Edits to turn expected into actual:\n    \
+             removed expected[0] 1"
+        );
+
+        assert_eq!(
+            sequence_diff(&[1], &[1, 2]),
+            "First mismatch at index 1: expected nothing (sequence ended) but was 2\n\
+             Actual had 1 extra element: [2]\n\n\
+             Edits to turn expected into actual:\n    \
+             inserted actual[1] 2"
+        );
+
+        assert_eq!(
+            sequence_diff(&[1, 2, 3], &[1]),
+            "First mismatch at index 1: expected 2 but was nothing (sequence ended)\n\
+             Actual was missing trailing elements [2, 3]\n\n\
+             Edits to turn expected into actual:\n    \
+             removed expected[1] 2\n    \
+             removed expected[2] 3"
+        );
+    }
+}
@@ -0,0 +1,189 @@
+use std::fmt::{Display, Formatter};
+
+/// A failed assertion, as returned by the non-panicking `try_should_*`
+/// variants of each extension.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssertionError {
+    /// What the value was expected to do, e.g. "should be less than".
+    pub verb: String,
+    /// The 'Debug' representation of the expected value, if the assertion
+    /// compared against one.
+    pub expected: Option<String>,
+    /// The description of the actual value, usually its 'Debug'
+    /// representation. May be empty when it would only repeat 'expected'.
+    pub actual: String,
+    /// A further explanation generated by the assertion, such as a diff.
+    pub details: Option<String>,
+    /// Extra details provided by the test author.
+    pub custom_message: Option<String>,
+}
+
+impl Display for AssertionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write_message(
+            f,
+            &self.verb,
+            self.expected.as_deref(),
+            &self.actual,
+            self.details.as_deref(),
+            self.custom_message.as_deref(),
+        )
+    }
+}
+
+impl std::error::Error for AssertionError {}
+
+/// The payload of the panic raised when an assertion fails. Tooling that
+/// catches the unwind can downcast to this type to inspect the failure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssertionFailure {
+    /// What the value was expected to do, e.g. "should be less than".
+    pub verb: String,
+    /// The 'Debug' representation of the expected value, if the assertion
+    /// compared against one.
+    pub expected: Option<String>,
+    /// The description of the actual value, usually its 'Debug'
+    /// representation. May be empty when it would only repeat 'expected'.
+    pub actual: String,
+    /// A further explanation generated by the assertion, such as a diff.
+    pub details: Option<String>,
+    /// Extra details provided by the test author.
+    pub custom_message: Option<String>,
+    /// The `file:line:column` the assertion was made from, if it could be
+    /// determined from the backtrace.
+    pub location: Option<String>,
+    /// The source of the value the assertion was made on, if it could be read.
+    pub asserted_expression: Option<String>,
+}
+
+impl AssertionFailure {
+    pub(crate) fn new(
+        error: AssertionError,
+        location: Option<String>,
+        asserted_expression: Option<String>,
+    ) -> Self {
+        Self {
+            verb: error.verb,
+            expected: error.expected,
+            actual: error.actual,
+            details: error.details,
+            custom_message: error.custom_message,
+            location,
+            asserted_expression,
+        }
+    }
+}
+
+impl Display for AssertionFailure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write_message(
+            f,
+            &self.verb,
+            self.expected.as_deref(),
+            &self.actual,
+            self.details.as_deref(),
+            self.custom_message.as_deref(),
+        )
+    }
+}
+
+/// The payload of the panic raised when a soft scope ends with failed
+/// assertions, holding each of them in the order they failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SoftAssertionFailures {
+    /// The failed assertions, each with its location and source.
+    pub failures: Vec<AssertionFailure>,
+}
+
+impl Display for SoftAssertionFailures {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let plural = if self.failures.len() == 1 { "" } else { "s" };
+        write!(f, "{} soft assertion{plural} failed:", self.failures.len())?;
+
+        for (index, failure) in self.failures.iter().enumerate() {
+            write!(
+                f,
+                "\n\n{}) at {}:\n'{}'{failure}",
+                index + 1,
+                failure.location.as_deref().unwrap_or("<unknown location>"),
+                failure
+                    .asserted_expression
+                    .as_deref()
+                    .unwrap_or("(could not get source)")
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl From<AssertionFailure> for AssertionError {
+    fn from(value: AssertionFailure) -> Self {
+        Self {
+            verb: value.verb,
+            expected: value.expected,
+            actual: value.actual,
+            details: value.details,
+            custom_message: value.custom_message,
+        }
+    }
+}
+
+fn write_message(
+    f: &mut Formatter<'_>,
+    verb: &str,
+    expected: Option<&str>,
+    actual: &str,
+    details: Option<&str>,
+    custom_message: Option<&str>,
+) -> std::fmt::Result {
+    write!(f, " {verb}")?;
+
+    if let Some(expected) = expected {
+        write!(f, " {expected}")?;
+    }
+
+    write!(f, " but was")?;
+
+    if !actual.is_empty() {
+        write!(f, " {actual}")?;
+    }
+
+    if let Some(details) = details {
+        write!(f, "\n\n{details}")?;
+    }
+
+    if let Some(msg) = custom_message {
+        write!(f, "\n\nExtra details: {msg}")?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ShouldBeEqExtension;
+
+    #[test]
+    fn panic_payload_is_assertion_failure() {
+        let value = 1;
+        let payload = std::panic::catch_unwind(|| value.should_be(&2)).unwrap_err();
+
+        let failure = payload
+            .downcast_ref::<AssertionFailure>()
+            .expect("payload should be an AssertionFailure");
+
+        assert_eq!(failure.verb, "should be");
+        assert_eq!(failure.expected.as_deref(), Some("2"));
+        assert_eq!(failure.actual, "1");
+        assert_eq!(failure.details, None);
+        assert_eq!(failure.custom_message, None);
+        assert_eq!(failure.asserted_expression.as_deref(), Some("value"));
+        assert!(failure
+            .location
+            .as_deref()
+            .is_some_and(|location| location.contains("src/error.rs:")));
+        assert_eq!(failure.to_string(), " should be 2 but was 1");
+    }
+}
@@ -1,4 +1,9 @@
-use crate::{assertions::assert_unary, context::AssertionContextBuilder, message_generator};
+use crate::{
+    assertions::{assert_result, check_unary},
+    context::AssertionContextBuilder,
+    error::AssertionError,
+    message_generator,
+};
 
 use std::fmt::Debug;
 
@@ -8,11 +13,26 @@ pub trait ShouldSatisfyExtension: Debug {
     ///
     /// More specialized functions should be preferred over this one.
     fn should_satisfy(&self, predicate: impl FnMut(&Self) -> bool, custom_message: Option<String>);
+
+    /// Non-panicking version of 'should_satisfy'.
+    fn try_should_satisfy(
+        &self,
+        predicate: impl FnMut(&Self) -> bool,
+        custom_message: Option<String>,
+    ) -> Result<(), AssertionError>;
 }
 
 impl<T: Debug> ShouldSatisfyExtension for T {
     fn should_satisfy(&self, predicate: impl FnMut(&Self) -> bool, custom_message: Option<String>) {
-        assert_unary(
+        assert_result(self.try_should_satisfy(predicate, custom_message));
+    }
+
+    fn try_should_satisfy(
+        &self,
+        predicate: impl FnMut(&Self) -> bool,
+        custom_message: Option<String>,
+    ) -> Result<(), AssertionError> {
+        check_unary(
             self,
             predicate,
             self,
@@ -20,6 +40,28 @@ impl<T: Debug> ShouldSatisfyExtension for T {
                 .verb("should satisfy the given predicate")
                 .custom_message(custom_message),
             message_generator::failed_condition_message,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn try_should_satisfy() {
+        5.try_should_satisfy(|&x| x > 3, None).unwrap();
+
+        let error = 5
+            .try_should_satisfy(|&x| x > 10, Some("too small".to_string()))
+            .unwrap_err();
+        assert_eq!(error.verb, "should satisfy the given predicate");
+        assert_eq!(error.expected, None);
+        assert_eq!(error.actual, "5");
+        assert_eq!(error.custom_message.as_deref(), Some("too small"));
+        assert_eq!(
+            error.to_string(),
+            " should satisfy the given predicate but was 5\n\nExtra details: too small"
         );
     }
 }
@@ -1,4 +1,9 @@
-use crate::{assertions::assert_comparison, context::AssertionContextBuilder, message_generator};
+use crate::{
+    assertions::{assert_result, check_comparison},
+    context::AssertionContextBuilder,
+    error::AssertionError,
+    message_generator,
+};
 
 use std::fmt::Debug;
 
@@ -8,6 +13,12 @@ pub trait ShouldBeEqExtension: Eq + Debug {
 
     /// Assert that this object is not equivalent to the given 'expected'.
     fn should_not_be(&self, expected: &Self);
+
+    /// Non-panicking version of 'should_be'.
+    fn try_should_be(&self, expected: &Self) -> Result<(), AssertionError>;
+
+    /// Non-panicking version of 'should_not_be'.
+    fn try_should_not_be(&self, expected: &Self) -> Result<(), AssertionError>;
 }
 
 impl<T> ShouldBeEqExtension for T
@@ -15,25 +26,33 @@ where
     T: Eq + Debug,
 {
     fn should_be(&self, expected: &Self) {
-        assert_comparison(
+        assert_result(self.try_should_be(expected));
+    }
+
+    fn should_not_be(&self, expected: &Self) {
+        assert_result(self.try_should_not_be(expected));
+    }
+
+    fn try_should_be(&self, expected: &Self) -> Result<(), AssertionError> {
+        check_comparison(
             self,
             |x| x == expected,
-            &self,
+            self,
             expected,
             AssertionContextBuilder::new(),
-            message_generator::expected_vs_actual_message,
-        );
+            message_generator::expected_vs_actual_diff_message,
+        )
     }
 
-    fn should_not_be(&self, expected: &Self) {
-        assert_comparison(
+    fn try_should_not_be(&self, expected: &Self) -> Result<(), AssertionError> {
+        check_comparison(
             self,
             |x| x != expected,
-            &self,
+            self,
             expected,
             AssertionContextBuilder::new().actual_mapper(Box::new(|_| "".to_string())),
             message_generator::expected_vs_actual_message,
-        );
+        )
     }
 }
 
@@ -85,4 +104,40 @@ mod tests {
         let result = std::panic::catch_unwind(|| original.should_not_be(&original));
         assert!(result.is_err());
     }
+
+    #[test]
+    fn try_should_be() {
+        let original = Object::new("object1", 1111);
+        let unique = Object::new("object2", 2222);
+
+        original.try_should_be(&original.clone()).unwrap();
+        original.try_should_not_be(&unique).unwrap();
+
+        let error = 1.try_should_be(&2).unwrap_err();
+        assert_eq!(error.verb, "should be");
+        assert_eq!(error.expected.as_deref(), Some("2"));
+        assert_eq!(error.actual, "1");
+        assert_eq!(error.to_string(), " should be 2 but was 1");
+
+        assert!(original.try_should_not_be(&original).is_err());
+    }
+
+    #[test]
+    fn should_be_diffs_multiline_values() {
+        let original = Object::new("object1", 1111);
+        let unique = Object::new("object2", 1111);
+
+        let error = original.try_should_be(&unique).unwrap_err();
+        let details = error.details.expect("a diff should be generated");
+        assert!(details.contains("-     internal_string: \"object2\","));
+        assert!(details.contains("+     internal_string: \"object1\","));
+        assert!(details.contains("      internal_u32: 1111,"));
+
+        let error = "one\ntwo".try_should_be(&"one\nthree").unwrap_err();
+        let details = error.details.expect("a diff should be generated");
+        assert!(details.contains("- three\""));
+        assert!(details.contains("+ two\""));
+
+        assert_eq!(1.try_should_be(&2).unwrap_err().details, None);
+    }
 }
@@ -0,0 +1,250 @@
+use crate::assertions::{assert_result, check_comparison, check_unary};
+use crate::context::AssertionContextBuilder;
+use crate::error::AssertionError;
+use crate::message_generator;
+
+use std::error::Error;
+use std::fmt::Debug;
+
+pub trait ShouldBeErrorExtension: Error {
+    /// Assert that the message of this error contains the given 'expected'.
+    fn should_have_message_containing(&self, expected: &str);
+
+    /// Assert that some error in the source chain of this error is of type
+    /// 'E'. The error itself is not considered.
+    fn should_have_source_of_type<E: Error + 'static>(&self);
+
+    /// Assert that the chain made up of this error and its sources has as
+    /// many errors as the given 'expected' has messages, and that the message
+    /// of each error contains its counterpart.
+    fn should_have_chain_matching<S: AsRef<str> + Debug>(
+        &self,
+        expected: impl IntoIterator<Item = S>,
+    );
+
+    /// Non-panicking version of 'should_have_message_containing'.
+    fn try_should_have_message_containing(&self, expected: &str) -> Result<(), AssertionError>;
+
+    /// Non-panicking version of 'should_have_source_of_type'.
+    fn try_should_have_source_of_type<E: Error + 'static>(&self) -> Result<(), AssertionError>;
+
+    /// Non-panicking version of 'should_have_chain_matching'.
+    fn try_should_have_chain_matching<S: AsRef<str> + Debug>(
+        &self,
+        expected: impl IntoIterator<Item = S>,
+    ) -> Result<(), AssertionError>;
+}
+
+impl<T: Error + ?Sized> ShouldBeErrorExtension for T {
+    fn should_have_message_containing(&self, expected: &str) {
+        assert_result(self.try_should_have_message_containing(expected));
+    }
+
+    fn should_have_source_of_type<E: Error + 'static>(&self) {
+        assert_result(self.try_should_have_source_of_type::<E>());
+    }
+
+    fn should_have_chain_matching<S: AsRef<str> + Debug>(
+        &self,
+        expected: impl IntoIterator<Item = S>,
+    ) {
+        assert_result(self.try_should_have_chain_matching(expected));
+    }
+
+    fn try_should_have_message_containing(&self, expected: &str) -> Result<(), AssertionError> {
+        let message = self.to_string();
+        let chain = chain_messages(self);
+
+        check_comparison(
+            message.as_str(),
+            |x| x.contains(expected),
+            message.as_str(),
+            expected,
+            AssertionContextBuilder::new().verb("should have a message containing"),
+            |actual, expected, context| {
+                let mut error =
+                    message_generator::expected_vs_actual_message(actual, expected, context);
+                error.details = Some(render_chain(&chain));
+                error
+            },
+        )
+    }
+
+    fn try_should_have_source_of_type<E: Error + 'static>(&self) -> Result<(), AssertionError> {
+        let message = self.to_string();
+        let chain = chain_messages(self);
+        let found = sources(self).any(|source| source.is::<E>());
+
+        check_unary(
+            message.as_str(),
+            |_| found,
+            message.as_str(),
+            AssertionContextBuilder::new().verb(&format!(
+                "should have a source of type {}",
+                std::any::type_name::<E>()
+            )),
+            |actual, context| {
+                let mut error = message_generator::failed_condition_message(actual, context);
+                error.details = Some(render_chain(&chain));
+                error
+            },
+        )
+    }
+
+    fn try_should_have_chain_matching<S: AsRef<str> + Debug>(
+        &self,
+        expected: impl IntoIterator<Item = S>,
+    ) -> Result<(), AssertionError> {
+        let expected: Vec<S> = expected.into_iter().collect();
+        let chain = chain_messages(self);
+
+        let mismatch = chain
+            .iter()
+            .zip(&expected)
+            .position(|(message, pattern)| !message.contains(pattern.as_ref()));
+        let passed = mismatch.is_none() && chain.len() == expected.len();
+
+        check_comparison(
+            &chain,
+            |_| passed,
+            &chain,
+            &expected,
+            AssertionContextBuilder::new().verb("should have a chain matching"),
+            |actual, expected, context| {
+                let mut details = Vec::new();
+                if let Some(index) = mismatch {
+                    details.push(format!(
+                        "First mismatch at index {index}: expected a message containing {:?} but was {:?}",
+                        expected[index], actual[index]
+                    ));
+                }
+                if actual.len() != expected.len() {
+                    details.push(format!(
+                        "Chain had {} error(s) but expected {}",
+                        actual.len(),
+                        expected.len()
+                    ));
+                }
+                details.push(render_chain(actual));
+
+                let mut error =
+                    message_generator::expected_vs_actual_message(actual, expected, context);
+                error.details = Some(details.join("\n\n"));
+                error
+            },
+        )
+    }
+}
+
+/// The sources of 'error', outermost first.
+fn sources<E: Error + ?Sized>(error: &E) -> impl Iterator<Item = &(dyn Error + 'static)> {
+    std::iter::successors(error.source(), |&source| source.source())
+}
+
+/// The messages of 'error' and each of its sources, outermost first.
+pub(crate) fn chain_messages<E: Error + ?Sized>(error: &E) -> Vec<String> {
+    std::iter::once(error.to_string())
+        .chain(sources(error).map(|source| source.to_string()))
+        .collect()
+}
+
+/// Render the messages of an error chain one per line, quoted the same way as
+/// the asserted expression is when an assertion fails.
+pub(crate) fn render_chain(messages: &[String]) -> String {
+    let mut rendered = "Error chain:".to_string();
+    for (index, message) in messages.iter().enumerate() {
+        rendered.push_str(&format!("\n    {index}: '{message}'"));
+    }
+    rendered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fmt::{Display, Formatter};
+
+    #[derive(Debug)]
+    struct Inner;
+
+    impl Display for Inner {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            write!(f, "connection refused")
+        }
+    }
+
+    impl Error for Inner {}
+
+    #[derive(Debug)]
+    struct Outer(Inner);
+
+    impl Display for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            write!(f, "failed to load config")
+        }
+    }
+
+    impl Error for Outer {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    fn boxed() -> Box<dyn Error> {
+        Box::new(Outer(Inner))
+    }
+
+    #[test]
+    fn should_have_message_containing() {
+        boxed().should_have_message_containing("load config");
+
+        let error = boxed()
+            .try_should_have_message_containing("refused")
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            " should have a message containing \"refused\" but was \"failed to load config\"\n\n\
+             Error chain:\n    \
+             0: 'failed to load config'\n    \
+             1: 'connection refused'"
+        );
+    }
+
+    #[test]
+    fn should_have_source_of_type() {
+        boxed().should_have_source_of_type::<Inner>();
+        Outer(Inner).should_have_source_of_type::<Inner>();
+
+        let error = boxed()
+            .try_should_have_source_of_type::<std::io::Error>()
+            .unwrap_err();
+        assert_eq!(
+            error.verb,
+            "should have a source of type std::io::error::Error"
+        );
+
+        // The error itself is not one of its sources.
+        let result = std::panic::catch_unwind(|| boxed().should_have_source_of_type::<Outer>());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_have_chain_matching() {
+        boxed().should_have_chain_matching(["load", "refused"]);
+
+        let error = boxed()
+            .try_should_have_chain_matching(["load", "timed out"])
+            .unwrap_err();
+        assert!(error.details.unwrap().starts_with(
+            "First mismatch at index 1: expected a message containing \"timed out\" but was \"connection refused\""
+        ));
+
+        let error = boxed()
+            .try_should_have_chain_matching(["load"])
+            .unwrap_err();
+        assert!(error
+            .details
+            .unwrap()
+            .starts_with("Chain had 2 error(s) but expected 1"));
+    }
+}
@@ -0,0 +1,376 @@
+use crate::assertions::{assert_result, check_comparison};
+use crate::config;
+use crate::context::AssertionContextBuilder;
+use crate::error::AssertionError;
+use crate::message_generator;
+
+use std::borrow::Borrow;
+use std::fmt::{Debug, Display, Formatter};
+
+/// How far apart two floating-point values may be while still being
+/// considered approximately equal.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tolerance {
+    /// The absolute difference may be at most the given value.
+    Absolute(f64),
+    /// The absolute difference may be at most the given fraction of the
+    /// larger magnitude of the two values.
+    Relative(f64),
+    /// The values may be at most the given number of representable values
+    /// ("units in the last place") apart.
+    Ulps(u64),
+}
+
+impl Display for Tolerance {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tolerance::Absolute(epsilon) => write!(f, "{epsilon:?}"),
+            Tolerance::Relative(ratio) => write!(f, "relative tolerance {ratio:?}"),
+            Tolerance::Ulps(ulps) => write!(f, "{ulps} ULPs"),
+        }
+    }
+}
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+}
+
+/// The floating-point types supported by the approximate assertions.
+pub trait ApproxFloat: Copy + Debug + private::Sealed {
+    #[doc(hidden)]
+    fn to_f64(self) -> f64;
+
+    /// The number of representable values between 'self' and 'other', or
+    /// 'None' if either is NaN.
+    #[doc(hidden)]
+    fn ulps_between(self, other: Self) -> Option<u64>;
+}
+
+impl ApproxFloat for f32 {
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    fn ulps_between(self, other: Self) -> Option<u64> {
+        if self.is_nan() || other.is_nan() {
+            return None;
+        }
+
+        // Map the bit patterns onto a scale that is monotonic in the value,
+        // with both zeroes at the same point.
+        let ordered = |x: f32| {
+            let bits = x.to_bits() as i32 as i64;
+            if bits < 0 {
+                i32::MIN as i64 - bits
+            } else {
+                bits
+            }
+        };
+        Some(ordered(self).abs_diff(ordered(other)))
+    }
+}
+
+impl ApproxFloat for f64 {
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn ulps_between(self, other: Self) -> Option<u64> {
+        if self.is_nan() || other.is_nan() {
+            return None;
+        }
+
+        // Map the bit patterns onto a scale that is monotonic in the value,
+        // with both zeroes at the same point.
+        let ordered = |x: f64| {
+            let bits = x.to_bits() as i64 as i128;
+            if bits < 0 {
+                i64::MIN as i128 - bits
+            } else {
+                bits
+            }
+        };
+        Some(u64::try_from(ordered(self).abs_diff(ordered(other))).unwrap_or(u64::MAX))
+    }
+}
+
+/// Compare 'actual' to 'expected' within 'tolerance', describing why they
+/// differ if they are not approximately equal.
+///
+/// NaN is only approximately equal to NaN, and infinities only to an infinity
+/// of the same sign.
+fn compare<F: ApproxFloat>(actual: F, expected: F, tolerance: Tolerance) -> Result<(), String> {
+    let (a, e) = (actual.to_f64(), expected.to_f64());
+
+    if a.is_nan() || e.is_nan() {
+        return match (a.is_nan(), e.is_nan()) {
+            (true, true) => Ok(()),
+            (true, false) => Err("Actual was NaN".to_string()),
+            _ => Err("Expected NaN".to_string()),
+        };
+    }
+
+    if a == e {
+        return Ok(());
+    }
+
+    if a.is_infinite() || e.is_infinite() {
+        return Err("Infinite values are only equal to themselves".to_string());
+    }
+
+    let difference = (a - e).abs();
+    match tolerance {
+        Tolerance::Absolute(epsilon) if difference <= epsilon => Ok(()),
+        Tolerance::Absolute(epsilon) => Err(format!(
+            "Difference was {difference:?}, exceeding the tolerance of {epsilon:?}"
+        )),
+        Tolerance::Relative(ratio) => {
+            let relative = difference / a.abs().max(e.abs());
+            if relative <= ratio {
+                Ok(())
+            } else {
+                Err(format!(
+                    "Difference was {difference:?} (relative {relative:?}), exceeding the relative tolerance of {ratio:?}"
+                ))
+            }
+        }
+        Tolerance::Ulps(max_ulps) => {
+            // 'ulps_between' is only 'None' for NaN, which was handled above.
+            let ulps = actual.ulps_between(expected).unwrap_or(u64::MAX);
+            if ulps <= max_ulps {
+                Ok(())
+            } else {
+                Err(format!(
+                    "Difference was {difference:?} ({ulps} ULPs), exceeding the tolerance of {max_ulps} ULPs"
+                ))
+            }
+        }
+    }
+}
+
+pub trait ShouldBeApproxExtension: ApproxFloat {
+    /// Assert that this value is approximately equal to the given 'expected',
+    /// i.e. that they differ by no more than the given 'tolerance'.
+    ///
+    /// NaN is only approximately equal to NaN.
+    fn should_be_approx(&self, expected: Self, tolerance: Tolerance);
+
+    /// Non-panicking version of 'should_be_approx'.
+    fn try_should_be_approx(
+        &self,
+        expected: Self,
+        tolerance: Tolerance,
+    ) -> Result<(), AssertionError>;
+}
+
+impl<T: ApproxFloat> ShouldBeApproxExtension for T {
+    fn should_be_approx(&self, expected: Self, tolerance: Tolerance) {
+        assert_result(self.try_should_be_approx(expected, tolerance));
+    }
+
+    fn try_should_be_approx(
+        &self,
+        expected: Self,
+        tolerance: Tolerance,
+    ) -> Result<(), AssertionError> {
+        let outcome = compare(*self, expected, tolerance);
+        let passed = outcome.is_ok();
+
+        check_comparison(
+            self,
+            |_| passed,
+            self,
+            expected,
+            AssertionContextBuilder::new().verb(&format!("should be within {tolerance} of")),
+            |actual, expected, context| {
+                let mut error =
+                    message_generator::expected_vs_actual_message(actual, expected, context);
+                error.details = outcome.err();
+                error
+            },
+        )
+    }
+}
+
+pub trait ShouldBeApproxIterExtension<F: ApproxFloat>:
+    IntoIterator<Item: Borrow<F>> + Clone + Debug
+{
+    /// Assert that the generated sequence has as many elements as the given
+    /// 'sequence', each approximately equal to its counterpart within the
+    /// given 'tolerance'.
+    fn should_all_be_approx(
+        self,
+        sequence: impl IntoIterator<Item = F> + Clone + Debug,
+        tolerance: Tolerance,
+    );
+
+    /// Non-panicking version of 'should_all_be_approx'.
+    fn try_should_all_be_approx(
+        self,
+        sequence: impl IntoIterator<Item = F> + Clone + Debug,
+        tolerance: Tolerance,
+    ) -> Result<(), AssertionError>;
+}
+
+impl<T, F> ShouldBeApproxIterExtension<F> for T
+where
+    T: Iterator<Item: Borrow<F>> + Clone + Debug,
+    F: ApproxFloat,
+{
+    fn should_all_be_approx(
+        self,
+        sequence: impl IntoIterator<Item = F> + Clone + Debug,
+        tolerance: Tolerance,
+    ) {
+        assert_result(self.try_should_all_be_approx(sequence, tolerance));
+    }
+
+    fn try_should_all_be_approx(
+        self,
+        sequence: impl IntoIterator<Item = F> + Clone + Debug,
+        tolerance: Tolerance,
+    ) -> Result<(), AssertionError> {
+        let actual: Vec<F> = self.clone().map(|x| *x.borrow()).collect();
+        let expected: Vec<F> = sequence.clone().into_iter().collect();
+
+        let mismatches: Vec<String> = actual
+            .iter()
+            .zip(&expected)
+            .enumerate()
+            .filter_map(|(index, (&a, &e))| {
+                compare(a, e, tolerance)
+                    .err()
+                    .map(|reason| format!("[{index}] expected {e:?} but was {a:?}: {reason}"))
+            })
+            .collect();
+        let lengths_match = actual.len() == expected.len();
+
+        let cloned = self.clone();
+
+        check_comparison(
+            self,
+            |_| lengths_match && mismatches.is_empty(),
+            cloned,
+            sequence,
+            AssertionContextBuilder::new().verb(&format!("should all be within {tolerance} of")),
+            |actual_iter, expected_iter, context| {
+                let mut details = Vec::new();
+                if !lengths_match {
+                    details.push(format!(
+                        "Actual had {} elements but expected {}",
+                        actual.len(),
+                        expected.len()
+                    ));
+                }
+                if !mismatches.is_empty() {
+                    let limit = config::max_reported_elements();
+                    let mut listing = format!(
+                        "{} of {} compared elements differed:",
+                        mismatches.len(),
+                        actual.len().min(expected.len())
+                    );
+                    for mismatch in mismatches.iter().take(limit) {
+                        listing.push_str(&format!("\n    {mismatch}"));
+                    }
+                    if mismatches.len() > limit {
+                        listing
+                            .push_str(&format!("\n    ... and {} more", mismatches.len() - limit));
+                    }
+                    details.push(listing);
+                }
+
+                let mut error = message_generator::expected_vs_actual_message(
+                    actual_iter,
+                    expected_iter,
+                    context,
+                );
+                error.details = Some(details.join("\n"));
+                error
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn absolute_tolerance() {
+        0.1f64.should_be_approx(0.1000001, Tolerance::Absolute(1e-6));
+        (0.1f32 + 0.2f32).should_be_approx(0.3, Tolerance::Absolute(1e-6));
+
+        let error = 1.1f64
+            .try_should_be_approx(1.0, Tolerance::Absolute(0.01))
+            .unwrap_err();
+        assert_eq!(error.verb, "should be within 0.01 of");
+        assert!(error
+            .details
+            .unwrap()
+            .ends_with("exceeding the tolerance of 0.01"));
+    }
+
+    #[test]
+    fn relative_tolerance() {
+        1_000_000.0f64.should_be_approx(1_000_001.0, Tolerance::Relative(1e-5));
+
+        let result =
+            std::panic::catch_unwind(|| 1.0f64.should_be_approx(1.1, Tolerance::Relative(1e-5)));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn ulps_tolerance() {
+        let next = f64::from_bits(1.0f64.to_bits() + 2);
+        1.0f64.should_be_approx(next, Tolerance::Ulps(2));
+        0.0f64.should_be_approx(-0.0, Tolerance::Ulps(0));
+        (-f32::MIN_POSITIVE).should_be_approx(f32::MIN_POSITIVE, Tolerance::Ulps(1 << 24));
+
+        let error = 1.0f64
+            .try_should_be_approx(next, Tolerance::Ulps(1))
+            .unwrap_err();
+        assert_eq!(error.verb, "should be within 1 ULPs of");
+        assert!(error.details.unwrap().contains("(2 ULPs)"));
+    }
+
+    #[test]
+    fn nan_and_infinity() {
+        f64::NAN.should_be_approx(f64::NAN, Tolerance::Absolute(0.0));
+        f64::INFINITY.should_be_approx(f64::INFINITY, Tolerance::Ulps(0));
+
+        let error = f64::NAN
+            .try_should_be_approx(1.0, Tolerance::Absolute(1e9))
+            .unwrap_err();
+        assert_eq!(error.details.as_deref(), Some("Actual was NaN"));
+
+        assert!(1.0f64
+            .try_should_be_approx(f64::NAN, Tolerance::Absolute(1e9))
+            .is_err());
+        assert!(f64::MAX
+            .try_should_be_approx(f64::INFINITY, Tolerance::Relative(1.0))
+            .is_err());
+    }
+
+    #[test]
+    fn should_all_be_approx() {
+        [1.0, 2.0, 3.0]
+            .iter()
+            .should_all_be_approx([1.0001, 2.0, 2.9999], Tolerance::Absolute(1e-3));
+
+        let error = [1.0, 2.5, 3.0, 4.5]
+            .iter()
+            .try_should_all_be_approx([1.0, 2.0, 3.0], Tolerance::Absolute(0.1))
+            .unwrap_err();
+        assert_eq!(
+            error.details.as_deref(),
+            Some(
+                "Actual had 4 elements but expected 3\n\
+                 1 of 3 compared elements differed:\n    \
+                 [1] expected 2.0 but was 2.5: Difference was 0.5, exceeding the tolerance of 0.1"
+            )
+        );
+    }
+}
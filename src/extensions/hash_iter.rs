@@ -0,0 +1,74 @@
+use crate::assertions::assert_result;
+use crate::error::AssertionError;
+use crate::extensions::iter::check_unique;
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+pub trait ShouldBeHashIterExtension: IntoIterator<Item: Hash + Eq + Debug> + Clone + Debug {
+    /// Assert that the generated sequence consists of unique items, using a
+    /// hash set rather than comparing every pair of items.
+    fn should_all_be_unique_hashed(self);
+
+    /// Non-panicking version of 'should_all_be_unique_hashed'.
+    fn try_should_all_be_unique_hashed(self) -> Result<(), AssertionError>;
+}
+
+impl<T> ShouldBeHashIterExtension for T
+where
+    T: Iterator<Item: Hash + Eq + Debug> + Clone + Debug,
+{
+    fn should_all_be_unique_hashed(self) {
+        assert_result(self.try_should_all_be_unique_hashed());
+    }
+
+    fn try_should_all_be_unique_hashed(self) -> Result<(), AssertionError> {
+        let items: Vec<_> = self.clone().collect();
+
+        // Groups of indices of equal items, in order of first occurrence.
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+        let mut group_of: HashMap<&T::Item, usize> = HashMap::new();
+        for (index, item) in items.iter().enumerate() {
+            match group_of.get(item) {
+                Some(&group) => groups[group].push(index),
+                None => {
+                    group_of.insert(item, groups.len());
+                    groups.push(vec![index]);
+                }
+            }
+        }
+
+        let duplicates = groups
+            .into_iter()
+            .filter(|indices| indices.len() > 1)
+            .collect();
+
+        check_unique(self, &items, duplicates)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_all_be_unique_hashed() {
+        (0..1000).should_all_be_unique_hashed();
+        std::iter::empty::<i32>().should_all_be_unique_hashed();
+
+        let error = ["a", "b", "b", "c", "a", "b"]
+            .iter()
+            .try_should_all_be_unique_hashed()
+            .unwrap_err();
+        assert_eq!(
+            error.details.as_deref(),
+            Some(
+                "Duplicated elements:\n    \"a\" at indices [0, 4]\n    \"b\" at indices [1, 2, 5]"
+            )
+        );
+
+        let result = std::panic::catch_unwind(|| [1, 1].iter().should_all_be_unique_hashed());
+        assert!(result.is_err());
+    }
+}
@@ -1,5 +1,7 @@
-use crate::assertions::{assert_comparison, assert_unary};
+use crate::assertions::{assert_result, check_comparison, check_unary};
+use crate::config;
 use crate::context::AssertionContextBuilder;
+use crate::error::AssertionError;
 use crate::message_generator;
 
 use std::fmt::Debug;
@@ -21,7 +23,10 @@ pub trait ShouldBeIntoIterExtension: IntoIterator<Item: Eq + Debug> + Clone + De
     fn should_be_size(self, size: usize);
 
     /// Assert that the generated sequence consists of unique items.
-    // fn should_all_be_unique(self);
+    ///
+    /// This compares every pair of items; for items implementing 'Hash',
+    /// 'should_all_be_unique_hashed' is faster on long sequences.
+    fn should_all_be_unique(self);
 
     /// Assert that the generated sequence contains the specified 'item'.
     fn should_contain(self, item: &Self::Item);
@@ -46,6 +51,90 @@ pub trait ShouldBeIntoIterExtension: IntoIterator<Item: Eq + Debug> + Clone + De
         matcher: impl FnMut(Self::Item) -> bool,
         custom_message: Option<String>,
     );
+
+    /// Assert that the generated sequence contains every element of the given
+    /// 'items', in any order. Repeated elements must occur at least as often
+    /// in the generated sequence.
+    fn should_contain_all(self, items: impl IntoIterator<Item = Self::Item> + Clone + Debug);
+
+    /// Assert that the generated sequence contains at least one element of the
+    /// given 'items'.
+    fn should_contain_any(self, items: impl IntoIterator<Item = Self::Item> + Clone + Debug);
+
+    /// Assert that the generated sequence contains none of the given 'items'.
+    fn should_contain_none(self, items: impl IntoIterator<Item = Self::Item> + Clone + Debug);
+
+    /// Assert that the generated sequence has exactly the same elements as the
+    /// given 'items', each occurring equally often, in any order.
+    fn should_be_permutation_of(self, items: impl IntoIterator<Item = Self::Item> + Clone + Debug);
+
+    /// Non-panicking version of 'should_be'.
+    fn try_should_be(
+        self,
+        sequence: impl IntoIterator<Item = Self::Item> + Clone + Debug,
+    ) -> Result<(), AssertionError>;
+
+    /// Non-panicking version of 'should_not_be'.
+    fn try_should_not_be(
+        self,
+        sequence: impl IntoIterator<Item = Self::Item> + Clone + Debug,
+    ) -> Result<(), AssertionError>;
+
+    /// Non-panicking version of 'should_be_empty'.
+    fn try_should_be_empty(self) -> Result<(), AssertionError>;
+
+    /// Non-panicking version of 'should_not_be_empty'.
+    fn try_should_not_be_empty(self) -> Result<(), AssertionError>;
+
+    /// Non-panicking version of 'should_be_size'.
+    fn try_should_be_size(self, size: usize) -> Result<(), AssertionError>;
+
+    /// Non-panicking version of 'should_all_be_unique'.
+    fn try_should_all_be_unique(self) -> Result<(), AssertionError>;
+
+    /// Non-panicking version of 'should_contain'.
+    fn try_should_contain(self, item: &Self::Item) -> Result<(), AssertionError>;
+
+    /// Non-panicking version of 'should_not_contain'.
+    fn try_should_not_contain(self, item: &Self::Item) -> Result<(), AssertionError>;
+
+    /// Non-panicking version of 'should_any_satisfy'.
+    fn try_should_any_satisfy(
+        self,
+        matcher: impl FnMut(Self::Item) -> bool,
+        custom_message: Option<String>,
+    ) -> Result<(), AssertionError>;
+
+    /// Non-panicking version of 'should_all_satisfy'.
+    fn try_should_all_satisfy(
+        self,
+        matcher: impl FnMut(Self::Item) -> bool,
+        custom_message: Option<String>,
+    ) -> Result<(), AssertionError>;
+
+    /// Non-panicking version of 'should_contain_all'.
+    fn try_should_contain_all(
+        self,
+        items: impl IntoIterator<Item = Self::Item> + Clone + Debug,
+    ) -> Result<(), AssertionError>;
+
+    /// Non-panicking version of 'should_contain_any'.
+    fn try_should_contain_any(
+        self,
+        items: impl IntoIterator<Item = Self::Item> + Clone + Debug,
+    ) -> Result<(), AssertionError>;
+
+    /// Non-panicking version of 'should_contain_none'.
+    fn try_should_contain_none(
+        self,
+        items: impl IntoIterator<Item = Self::Item> + Clone + Debug,
+    ) -> Result<(), AssertionError>;
+
+    /// Non-panicking version of 'should_be_permutation_of'.
+    fn try_should_be_permutation_of(
+        self,
+        items: impl IntoIterator<Item = Self::Item> + Clone + Debug,
+    ) -> Result<(), AssertionError>;
 }
 
 impl<T> ShouldBeIntoIterExtension for T
@@ -53,61 +142,131 @@ where
     T: Iterator<Item: Eq + Debug> + Clone + Debug,
 {
     fn should_be(self, sequence: impl IntoIterator<Item = Self::Item> + Clone + Debug) {
+        assert_result(self.try_should_be(sequence));
+    }
+
+    fn should_not_be(self, sequence: impl IntoIterator<Item = Self::Item> + Clone + Debug) {
+        assert_result(self.try_should_not_be(sequence));
+    }
+
+    fn should_be_empty(self) {
+        assert_result(self.try_should_be_empty());
+    }
+
+    fn should_not_be_empty(self) {
+        assert_result(self.try_should_not_be_empty());
+    }
+
+    fn should_be_size(self, size: usize) {
+        assert_result(self.try_should_be_size(size));
+    }
+
+    fn should_all_be_unique(self) {
+        assert_result(self.try_should_all_be_unique());
+    }
+
+    fn should_contain(self, item: &Self::Item) {
+        assert_result(self.try_should_contain(item));
+    }
+
+    fn should_not_contain(self, item: &Self::Item) {
+        assert_result(self.try_should_not_contain(item));
+    }
+
+    fn should_any_satisfy(
+        self,
+        predicate: impl FnMut(Self::Item) -> bool,
+        custom_message: Option<String>,
+    ) {
+        assert_result(self.try_should_any_satisfy(predicate, custom_message));
+    }
+
+    fn should_all_satisfy(
+        self,
+        predicate: impl FnMut(Self::Item) -> bool,
+        custom_message: Option<String>,
+    ) {
+        assert_result(self.try_should_all_satisfy(predicate, custom_message));
+    }
+
+    fn should_contain_all(self, items: impl IntoIterator<Item = Self::Item> + Clone + Debug) {
+        assert_result(self.try_should_contain_all(items));
+    }
+
+    fn should_contain_any(self, items: impl IntoIterator<Item = Self::Item> + Clone + Debug) {
+        assert_result(self.try_should_contain_any(items));
+    }
+
+    fn should_contain_none(self, items: impl IntoIterator<Item = Self::Item> + Clone + Debug) {
+        assert_result(self.try_should_contain_none(items));
+    }
+
+    fn should_be_permutation_of(self, items: impl IntoIterator<Item = Self::Item> + Clone + Debug) {
+        assert_result(self.try_should_be_permutation_of(items));
+    }
+
+    fn try_should_be(
+        self,
+        sequence: impl IntoIterator<Item = Self::Item> + Clone + Debug,
+    ) -> Result<(), AssertionError> {
         let cloned_self = self.clone();
         let cloned_other = sequence.clone();
 
-        assert_comparison(
+        check_comparison(
             self,
-            |iter| iter.zip(sequence).all(|(x, y)| x == y),
+            |iter| iter.eq(sequence),
             cloned_self,
             cloned_other,
             AssertionContextBuilder::new(),
-            message_generator::expected_vs_actual_message,
-        );
+            message_generator::sequence_diff_message,
+        )
     }
 
-    fn should_not_be(self, sequence: impl IntoIterator<Item = Self::Item> + Clone + Debug) {
+    fn try_should_not_be(
+        self,
+        sequence: impl IntoIterator<Item = Self::Item> + Clone + Debug,
+    ) -> Result<(), AssertionError> {
         let cloned_self = self.clone();
         let cloned_other = sequence.clone();
 
-        assert_comparison(
+        check_comparison(
             self,
-            |iter| iter.zip(sequence).any(|(x, y)| x != y),
+            |iter| iter.ne(sequence),
             cloned_self,
             cloned_other,
-            AssertionContextBuilder::new(),
+            AssertionContextBuilder::new().verb("should not be"),
             message_generator::expected_vs_actual_message,
-        );
+        )
     }
 
-    fn should_be_empty(self) {
+    fn try_should_be_empty(self) -> Result<(), AssertionError> {
         let cloned = self.clone();
 
-        assert_unary(
+        check_unary(
             self,
             |mut iter| iter.next().is_none(),
             cloned,
             AssertionContextBuilder::new().verb("should be empty"),
             message_generator::failed_condition_message,
-        );
+        )
     }
 
-    fn should_not_be_empty(self) {
+    fn try_should_not_be_empty(self) -> Result<(), AssertionError> {
         let cloned = self.clone();
 
-        assert_unary(
+        check_unary(
             self,
             |mut iter| iter.next().is_some(),
             cloned,
             AssertionContextBuilder::new().verb("should not be empty"),
             message_generator::failed_condition_message,
-        );
+        )
     }
 
-    fn should_be_size(self, size: usize) {
+    fn try_should_be_size(self, size: usize) -> Result<(), AssertionError> {
         let cloned = self.clone();
 
-        assert_unary(
+        check_unary(
             self,
             |iter| {
                 let mut count: usize = 0;
@@ -127,69 +286,312 @@ where
                     format!(" size {count}: {iter:?}")
                 })),
             message_generator::failed_condition_message,
-        );
+        )
     }
 
-    fn should_contain(self, item: &Self::Item) {
+    fn try_should_all_be_unique(self) -> Result<(), AssertionError> {
+        let items: Vec<_> = self.clone().collect();
+
+        let mut duplicates: Vec<Vec<usize>> = Vec::new();
+        let mut seen = vec![false; items.len()];
+        for i in 0..items.len() {
+            if seen[i] {
+                continue;
+            }
+
+            let mut indices = vec![i];
+            for j in i + 1..items.len() {
+                if !seen[j] && items[j] == items[i] {
+                    seen[j] = true;
+                    indices.push(j);
+                }
+            }
+
+            if indices.len() > 1 {
+                duplicates.push(indices);
+            }
+        }
+
+        check_unique(self, &items, duplicates)
+    }
+
+    fn try_should_contain(self, item: &Self::Item) -> Result<(), AssertionError> {
         let cloned = self.clone();
 
-        assert_comparison(
+        check_comparison(
             self,
             |mut iter: Self| iter.any(|x| x == *item),
             cloned,
             item,
             AssertionContextBuilder::new().verb("should contain"),
             message_generator::expected_vs_actual_message,
-        );
+        )
     }
 
-    fn should_not_contain(self, item: &Self::Item) {
+    fn try_should_not_contain(self, item: &Self::Item) -> Result<(), AssertionError> {
         let cloned = self.clone();
 
-        assert_comparison(
+        check_comparison(
             self,
             |mut iter: Self| !iter.any(|x| x == *item),
             cloned,
             item,
             AssertionContextBuilder::new().verb("should not contain"),
             message_generator::expected_vs_actual_message,
-        );
+        )
     }
 
-    fn should_any_satisfy(
+    fn try_should_any_satisfy(
         self,
-        predicate: impl FnMut(Self::Item) -> bool,
+        mut predicate: impl FnMut(Self::Item) -> bool,
         custom_message: Option<String>,
-    ) {
+    ) -> Result<(), AssertionError> {
         let cloned = self.clone();
 
-        assert_unary(
+        let mut checked: usize = 0;
+        let satisfied = self.clone().any(|x| {
+            checked += 1;
+            predicate(x)
+        });
+
+        check_unary(
             self,
-            |mut iter: Self| iter.any(predicate),
+            |_| satisfied,
             cloned,
             AssertionContextBuilder::new()
                 .verb("should satisfy the predicate for at least one element")
                 .custom_message(custom_message),
-            message_generator::failed_condition_message,
-        );
+            |actual, context| {
+                let mut error = message_generator::failed_condition_message(actual, context);
+                let plural = if checked == 1 { "" } else { "s" };
+                error.details = Some(format!(
+                    "Checked {checked} element{plural}, none of which satisfied the predicate"
+                ));
+                error
+            },
+        )
     }
 
-    fn should_all_satisfy(
+    fn try_should_all_satisfy(
         self,
-        predicate: impl FnMut(Self::Item) -> bool,
+        mut predicate: impl FnMut(Self::Item) -> bool,
         custom_message: Option<String>,
-    ) {
+    ) -> Result<(), AssertionError> {
         let cloned = self.clone();
 
-        assert_unary(
+        let mut checked: usize = 0;
+        let failing: Vec<usize> = self
+            .clone()
+            .inspect(|_| checked += 1)
+            .enumerate()
+            .filter_map(|(index, x)| (!predicate(x)).then_some(index))
+            .collect();
+
+        check_unary(
             self,
-            |mut iter: Self| iter.all(predicate),
+            |_| failing.is_empty(),
             cloned,
             AssertionContextBuilder::new()
                 .verb("should satisfy the predicate for all elements")
                 .custom_message(custom_message),
-            message_generator::failed_condition_message,
-        );
+            |actual: Self, context| {
+                let limit = config::max_reported_elements();
+
+                let mut details = format!(
+                    "{} of {checked} elements did not satisfy the predicate:",
+                    failing.len()
+                );
+                let mut remaining = failing.iter().take(limit).peekable();
+                for (index, x) in actual.clone().enumerate() {
+                    if remaining.next_if_eq(&&index).is_some() {
+                        details.push_str(&format!("\n    [{index}] {x:?}"));
+                    }
+                    if remaining.peek().is_none() {
+                        break;
+                    }
+                }
+                if failing.len() > limit {
+                    details.push_str(&format!("\n    ... and {} more", failing.len() - limit));
+                }
+
+                let mut error = message_generator::failed_condition_message(actual, context);
+                error.details = Some(details);
+                error
+            },
+        )
+    }
+
+    fn try_should_contain_all(
+        self,
+        items: impl IntoIterator<Item = Self::Item> + Clone + Debug,
+    ) -> Result<(), AssertionError> {
+        let cloned_self = self.clone();
+        let cloned_items = items.clone();
+
+        check_comparison(
+            self,
+            |iter| multiset_difference(items, iter).missing.is_empty(),
+            cloned_self,
+            cloned_items,
+            AssertionContextBuilder::new().verb("should contain all of"),
+            |actual, expected, context| {
+                let difference = multiset_difference(expected.clone(), actual.clone());
+
+                let mut error =
+                    message_generator::expected_vs_actual_message(actual, expected, context);
+                error.details = Some(format!("Missing elements: {:?}", difference.missing));
+                error
+            },
+        )
+    }
+
+    fn try_should_contain_any(
+        self,
+        items: impl IntoIterator<Item = Self::Item> + Clone + Debug,
+    ) -> Result<(), AssertionError> {
+        let cloned_self = self.clone();
+        let cloned_items = items.clone();
+
+        check_comparison(
+            self,
+            |iter| {
+                let actual: Vec<_> = iter.collect();
+                items.into_iter().any(|item| actual.contains(&item))
+            },
+            cloned_self,
+            cloned_items,
+            AssertionContextBuilder::new().verb("should contain any of"),
+            message_generator::expected_vs_actual_message,
+        )
+    }
+
+    fn try_should_contain_none(
+        self,
+        items: impl IntoIterator<Item = Self::Item> + Clone + Debug,
+    ) -> Result<(), AssertionError> {
+        let cloned_self = self.clone();
+        let cloned_items = items.clone();
+
+        check_comparison(
+            self,
+            |iter| {
+                let items: Vec<_> = items.into_iter().collect();
+                !iter.into_iter().any(|x| items.contains(&x))
+            },
+            cloned_self,
+            cloned_items,
+            AssertionContextBuilder::new().verb("should contain none of"),
+            |actual, expected, context| {
+                let items: Vec<_> = expected.clone().into_iter().collect();
+                let found: Vec<_> = actual
+                    .clone()
+                    .enumerate()
+                    .filter(|(_, x)| items.contains(x))
+                    .map(|(index, x)| format!("{x:?} at index {index}"))
+                    .collect();
+
+                let mut error =
+                    message_generator::expected_vs_actual_message(actual, expected, context);
+                error.details = Some(format!("Unexpected elements: [{}]", found.join(", ")));
+                error
+            },
+        )
+    }
+
+    fn try_should_be_permutation_of(
+        self,
+        items: impl IntoIterator<Item = Self::Item> + Clone + Debug,
+    ) -> Result<(), AssertionError> {
+        let cloned_self = self.clone();
+        let cloned_items = items.clone();
+
+        check_comparison(
+            self,
+            |iter| multiset_difference(items, iter).is_empty(),
+            cloned_self,
+            cloned_items,
+            AssertionContextBuilder::new().verb("should be a permutation of"),
+            |actual, expected, context| {
+                let difference = multiset_difference(expected.clone(), actual.clone());
+
+                let mut error =
+                    message_generator::expected_vs_actual_message(actual, expected, context);
+                error.details = Some(format!(
+                    "Missing elements: {:?}\nUnexpected elements: {:?}",
+                    difference.missing, difference.unexpected
+                ));
+                error
+            },
+        )
+    }
+}
+
+/// Check that no element generated by 'iter' is duplicated, given the
+/// 'duplicates' found in its 'items' as groups of indices of equal elements.
+pub(crate) fn check_unique<I>(
+    iter: I,
+    items: &[I::Item],
+    duplicates: Vec<Vec<usize>>,
+) -> Result<(), AssertionError>
+where
+    I: Iterator<Item: Debug> + Clone + Debug,
+{
+    let cloned = iter.clone();
+
+    check_unary(
+        iter,
+        |_| duplicates.is_empty(),
+        cloned,
+        AssertionContextBuilder::new().verb("should all be unique"),
+        |actual, context| {
+            let mut error = message_generator::failed_condition_message(actual, context);
+
+            let mut details = String::from("Duplicated elements:");
+            for indices in &duplicates {
+                details.push_str(&format!(
+                    "\n    {:?} at indices {indices:?}",
+                    items[indices[0]]
+                ));
+            }
+            error.details = Some(details);
+
+            error
+        },
+    )
+}
+
+/// The elements left over after pairing up equal elements of an expected and
+/// an actual collection, each element being paired at most once.
+struct MultisetDifference<T> {
+    /// Expected elements without an equal actual element.
+    missing: Vec<T>,
+    /// Actual elements without an equal expected element.
+    unexpected: Vec<T>,
+}
+
+impl<T> MultisetDifference<T> {
+    fn is_empty(&self) -> bool {
+        self.missing.is_empty() && self.unexpected.is_empty()
+    }
+}
+
+fn multiset_difference<T: PartialEq>(
+    expected: impl IntoIterator<Item = T>,
+    actual: impl IntoIterator<Item = T>,
+) -> MultisetDifference<T> {
+    let mut unmatched: Vec<Option<T>> = actual.into_iter().map(Some).collect();
+    let mut missing = Vec::new();
+
+    for item in expected {
+        match unmatched.iter().position(|x| x.as_ref() == Some(&item)) {
+            Some(position) => unmatched[position] = None,
+            None => missing.push(item),
+        }
+    }
+
+    MultisetDifference {
+        missing,
+        unexpected: unmatched.into_iter().flatten().collect(),
     }
 }
 
@@ -197,6 +599,56 @@ where
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_should_be() {
+        (0..3).should_be([0, 1, 2]);
+
+        let result = std::panic::catch_unwind(|| (0..3).should_be([0, 2, 1]));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_should_be_length_mismatch() {
+        // Regression: a shorter or longer sequence sharing a common prefix
+        // used to pass, as only the zipped elements were compared.
+        let result = std::panic::catch_unwind(|| [1, 2].iter().should_be(&[1, 2, 3]));
+        assert!(result.is_err());
+
+        let result = std::panic::catch_unwind(|| [1, 2, 3].iter().should_be(&[1, 2]));
+        assert!(result.is_err());
+
+        let result = std::panic::catch_unwind(|| std::iter::empty::<i32>().should_be([1]));
+        assert!(result.is_err());
+
+        let error = [1, 2, 3, 4].iter().try_should_be(&[1, 2]).unwrap_err();
+        let details = error.details.expect("a sequence diff should be generated");
+        assert!(details.contains("Actual had 2 extra elements: [3, 4]"));
+
+        let error = [1].iter().try_should_be(&[1, 2, 3]).unwrap_err();
+        let details = error.details.expect("a sequence diff should be generated");
+        assert!(details.contains("Actual was missing trailing elements [2, 3]"));
+    }
+
+    #[test]
+    fn test_should_not_be() {
+        (0..3).should_not_be([0, 2, 1]);
+
+        let result = std::panic::catch_unwind(|| (0..3).should_not_be([0, 1, 2]));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_should_not_be_length_mismatch() {
+        // Regression: sequences sharing a common prefix used to be considered
+        // the same regardless of their lengths.
+        [1, 2].iter().should_not_be(&[1, 2, 3]);
+        [1, 2, 3].iter().should_not_be(&[1, 2]);
+        std::iter::empty::<i32>().should_not_be([1]);
+
+        let error = (0..2).try_should_not_be([0, 1]).unwrap_err();
+        assert_eq!(error.verb, "should not be");
+    }
+
     #[test]
     fn test_should_be_empty() {
         std::iter::empty::<i32>().should_be_empty();
@@ -281,4 +733,147 @@ mod tests {
         let result = std::panic::catch_unwind(|| (0..3).should_all_satisfy(|x| x < 1, None));
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_should_all_satisfy_reports_failing_elements() {
+        let error = (0..6)
+            .map(|x| x * 10)
+            .try_should_all_satisfy(|x| x % 20 == 0, None)
+            .unwrap_err();
+        assert_eq!(
+            error.details.as_deref(),
+            Some(
+                "3 of 6 elements did not satisfy the predicate:\n    \
+                 [1] 10\n    [3] 30\n    [5] 50"
+            )
+        );
+
+        // Only the first few failing elements are listed.
+        let error = (0..1000)
+            .try_should_all_satisfy(|x| x < 5, None)
+            .unwrap_err();
+        let details = error.details.unwrap();
+        assert!(details.starts_with("995 of 1000 elements did not satisfy the predicate:"));
+        assert!(details.contains("[5] 5\n"));
+        assert!(details.contains("[14] 14\n"));
+        assert!(!details.contains("[15] 15"));
+        assert!(details.ends_with("... and 985 more"));
+    }
+
+    #[test]
+    fn test_should_any_satisfy_reports_checked_count() {
+        let error = (0..1000)
+            .try_should_any_satisfy(|x| x > 1000, None)
+            .unwrap_err();
+        assert_eq!(
+            error.details.as_deref(),
+            Some("Checked 1000 elements, none of which satisfied the predicate")
+        );
+    }
+
+    #[test]
+    fn test_try_should_contain() {
+        (0..3).try_should_contain(&1).unwrap();
+
+        let error = (0..3).try_should_contain(&10).unwrap_err();
+        assert_eq!(error.verb, "should contain");
+        assert_eq!(error.expected.as_deref(), Some("10"));
+        assert_eq!(error.actual, "0..3");
+    }
+
+    #[test]
+    fn test_try_should_all_satisfy() {
+        (0..3).try_should_all_satisfy(|x| x < 5, None).unwrap();
+
+        let error = (0..3)
+            .try_should_all_satisfy(|x| x < 1, Some("small".to_string()))
+            .unwrap_err();
+        assert_eq!(error.custom_message.as_deref(), Some("small"));
+    }
+
+    #[test]
+    fn test_should_be_reports_divergence() {
+        let error = [1, 3, 2].iter().try_should_be(&[1, 2, 3]).unwrap_err();
+        let details = error.details.expect("a sequence diff should be generated");
+        assert!(details.starts_with("First mismatch at index 1: expected 2 but was 3"));
+        assert!(details.contains("removed expected[1] 2"));
+        assert!(details.contains("inserted actual[2] 2"));
+    }
+
+    #[test]
+    fn test_should_contain_all() {
+        [3, 1, 2, 1].iter().should_contain_all(&[1, 2, 1]);
+        (0..5).should_contain_all([]);
+
+        let error = [3, 1, 2]
+            .iter()
+            .try_should_contain_all(&[1, 1, 4])
+            .unwrap_err();
+        assert_eq!(error.verb, "should contain all of");
+        assert_eq!(error.details.as_deref(), Some("Missing elements: [1, 4]"));
+
+        let result = std::panic::catch_unwind(|| (0..3).should_contain_all([5]));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_should_contain_any() {
+        (0..5).should_contain_any([10, 4]);
+
+        let result = std::panic::catch_unwind(|| (0..5).should_contain_any([10, 11]));
+        assert!(result.is_err());
+
+        let result = std::panic::catch_unwind(|| (0..5).should_contain_any([]));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_should_contain_none() {
+        (0..5).should_contain_none([10, 11]);
+        (0..5).should_contain_none([]);
+
+        let error = (0..5).try_should_contain_none([1, 10, 3]).unwrap_err();
+        assert_eq!(
+            error.details.as_deref(),
+            Some("Unexpected elements: [1 at index 1, 3 at index 3]")
+        );
+    }
+
+    #[test]
+    fn test_should_be_permutation_of() {
+        [3, 1, 2, 1].iter().should_be_permutation_of(&[1, 1, 2, 3]);
+        std::iter::empty::<i32>().should_be_permutation_of([]);
+
+        let error = [3, 1, 2, 2]
+            .iter()
+            .try_should_be_permutation_of(&[1, 1, 2, 3])
+            .unwrap_err();
+        assert_eq!(error.verb, "should be a permutation of");
+        assert_eq!(
+            error.details.as_deref(),
+            Some("Missing elements: [1]\nUnexpected elements: [2]")
+        );
+
+        let result = std::panic::catch_unwind(|| (0..3).should_be_permutation_of([0, 1]));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_should_all_be_unique() {
+        (0..5).should_all_be_unique();
+        std::iter::empty::<i32>().should_all_be_unique();
+
+        let error = [1, 2, 2, 3, 1, 2]
+            .iter()
+            .try_should_all_be_unique()
+            .unwrap_err();
+        assert_eq!(error.verb, "should all be unique");
+        assert_eq!(
+            error.details.as_deref(),
+            Some("Duplicated elements:\n    1 at indices [0, 4]\n    2 at indices [1, 2, 5]")
+        );
+
+        let result = std::panic::catch_unwind(|| [1, 1].iter().should_all_be_unique());
+        assert!(result.is_err());
+    }
 }
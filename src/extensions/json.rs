@@ -0,0 +1,183 @@
+use crate::assertions::{assert_result, check_comparison};
+use crate::context::AssertionContextBuilder;
+use crate::error::AssertionError;
+use crate::message_generator;
+use crate::tree::{compare, describe_mismatches, Tree};
+
+use serde_json::Value;
+use std::fmt::{Debug, Formatter};
+
+/// How JSON values are compared by 'should_be_json_eq_with'. By default, the
+/// values must be equal apart from the order of object keys.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JsonOptions {
+    /// Allow objects in the actual value to have fields the expected value
+    /// does not. Arrays must still have the same length.
+    pub subset: bool,
+    /// JSON pointers (RFC 6901, e.g. "/items/0/id") to leave out of the
+    /// comparison on both sides.
+    pub ignored: Vec<String>,
+}
+
+impl JsonOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn subset(mut self) -> Self {
+        self.subset = true;
+        self
+    }
+
+    pub fn ignore(mut self, pointer: &str) -> Self {
+        self.ignored.push(pointer.to_string());
+        self
+    }
+}
+
+pub trait ShouldBeJsonExtension {
+    /// Assert that this JSON value equals the given 'expected', regardless of
+    /// the order of object keys.
+    fn should_be_json_eq(&self, expected: &Value);
+
+    /// Assert that this JSON value equals the given 'expected', as configured
+    /// by 'options'.
+    fn should_be_json_eq_with(&self, expected: &Value, options: &JsonOptions);
+
+    /// Non-panicking version of 'should_be_json_eq'.
+    fn try_should_be_json_eq(&self, expected: &Value) -> Result<(), AssertionError>;
+
+    /// Non-panicking version of 'should_be_json_eq_with'.
+    fn try_should_be_json_eq_with(
+        &self,
+        expected: &Value,
+        options: &JsonOptions,
+    ) -> Result<(), AssertionError>;
+}
+
+impl ShouldBeJsonExtension for Value {
+    fn should_be_json_eq(&self, expected: &Value) {
+        assert_result(self.try_should_be_json_eq(expected));
+    }
+
+    fn should_be_json_eq_with(&self, expected: &Value, options: &JsonOptions) {
+        assert_result(self.try_should_be_json_eq_with(expected, options));
+    }
+
+    fn try_should_be_json_eq(&self, expected: &Value) -> Result<(), AssertionError> {
+        self.try_should_be_json_eq_with(expected, &JsonOptions::new())
+    }
+
+    fn try_should_be_json_eq_with(
+        &self,
+        expected: &Value,
+        options: &JsonOptions,
+    ) -> Result<(), AssertionError> {
+        let mut mismatches = Vec::new();
+        compare(
+            &Tree::from(expected),
+            &Tree::from(self),
+            "$",
+            "",
+            options,
+            &mut mismatches,
+        );
+        let passed = mismatches.is_empty();
+
+        let verb = if options.subset {
+            "should be a JSON superset of"
+        } else {
+            "should be JSON equal to"
+        };
+
+        check_comparison(
+            Json(self),
+            |_| passed,
+            Json(self),
+            Json(expected),
+            AssertionContextBuilder::new().verb(verb),
+            |actual, expected, context| {
+                let mut error =
+                    message_generator::expected_vs_actual_message(actual, expected, context);
+                error.details = Some(describe_mismatches(&mismatches));
+                error
+            },
+        )
+    }
+}
+
+/// Formats a JSON value as compact JSON rather than as a 'Value' tree.
+pub(crate) struct Json<'a>(pub &'a Value);
+
+impl Debug for Json<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn ignores_key_order() {
+        json!({"a": 1, "b": [1, 2.0]}).should_be_json_eq(&json!({"b": [1.0, 2], "a": 1}));
+
+        // Beyond 2^53, distinct integers share an f64 but must not compare equal.
+        let result = json!(9007199254740993u64).try_should_be_json_eq(&json!(9007199254740992u64));
+        assert!(result.is_err());
+
+        let result = std::panic::catch_unwind(|| json!([1, 2]).should_be_json_eq(&json!([2, 1])));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn reports_mismatches_by_path() {
+        let actual = json!({
+            "items": [{"price": 10}, {"price": 12, "sku": "b"}],
+            "extra": true,
+            "my key": 1
+        });
+        let expected = json!({
+            "items": [{"price": 10}, {"price": 10}, {"price": 3}],
+            "total": 20,
+            "my key": null
+        });
+
+        let error = actual.try_should_be_json_eq(&expected).unwrap_err();
+        assert_eq!(error.verb, "should be JSON equal to");
+        assert_eq!(
+            error.details.as_deref(),
+            Some(
+                "6 mismatch(es):\n    \
+                 $.items[1].price: expected 10, was 12\n    \
+                 $.items[1].sku: expected nothing, was \"b\"\n    \
+                 $.items[2]: expected {\"price\":3}, was missing\n    \
+                 $[\"my key\"]: expected null, was 1\n    \
+                 $.total: expected 20, was missing\n    \
+                 $.extra: expected nothing, was true"
+            )
+        );
+    }
+
+    #[test]
+    fn subset_and_ignored_pointers() {
+        let actual = json!({"id": "x1", "name": "a", "meta": {"created/at": 5, "v": 1}});
+
+        actual.should_be_json_eq_with(&json!({"name": "a"}), &JsonOptions::new().subset());
+        actual.should_be_json_eq_with(
+            &json!({"id": "other", "name": "a", "meta": {"v": 1}}),
+            &JsonOptions::new().ignore("/id").ignore("/meta/created~1at"),
+        );
+
+        let error = actual
+            .try_should_be_json_eq_with(&json!({"name": "b"}), &JsonOptions::new().subset())
+            .unwrap_err();
+        assert_eq!(error.verb, "should be a JSON superset of");
+        assert_eq!(
+            error.details.as_deref(),
+            Some("1 mismatch(es):\n    $.name: expected \"b\", was \"a\"")
+        );
+    }
+}
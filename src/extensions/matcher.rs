@@ -0,0 +1,115 @@
+use crate::{
+    assertions::{assert_result, check_unary},
+    context::AssertionContextBuilder,
+    error::AssertionError,
+    matchers::Matcher,
+    message_generator,
+};
+
+use std::fmt::Debug;
+
+pub trait ShouldMatchExtension: Debug {
+    /// Assert that this value satisfies the given 'matcher'.
+    fn should(&self, matcher: impl Matcher<Self>);
+
+    /// Non-panicking version of 'should'.
+    fn try_should(&self, matcher: impl Matcher<Self>) -> Result<(), AssertionError>;
+}
+
+impl<T: Debug + ?Sized> ShouldMatchExtension for T {
+    fn should(&self, matcher: impl Matcher<Self>) {
+        assert_result(self.try_should(matcher));
+    }
+
+    fn try_should(&self, matcher: impl Matcher<Self>) -> Result<(), AssertionError> {
+        check_unary(
+            self,
+            |x| matcher.matches(x).is_match(),
+            self,
+            AssertionContextBuilder::new().verb("should match"),
+            |actual, context| {
+                let mut error = message_generator::failed_condition_message(actual, context);
+                error.expected = Some(matcher.describe());
+                error.details = matcher.describe_mismatch(actual);
+                error
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matchers::{all_of, contains, each, eq, gt, has_field, lt, not, MatchResult};
+
+    #[derive(Debug)]
+    struct Order {
+        id: u32,
+        items: Vec<&'static str>,
+    }
+
+    #[test]
+    fn should() {
+        5.should(all_of([gt(1).boxed(), lt(10).boxed()]));
+        "hello".should(contains("ell"));
+        vec![1, 2, 3].should(each(not(eq(0))));
+
+        let error = 7.try_should(lt(5)).unwrap_err();
+        assert_eq!(error.to_string(), " should match less than 5 but was 7");
+
+        let error = vec![1, 7].try_should(each(lt(5))).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            " should match every element less than 5 but was [1, 7]\n\n[1] was 7"
+        );
+
+        let result = std::panic::catch_unwind(|| 7.should(lt(5)));
+        assert!(result.is_err());
+    }
+
+    struct Even;
+
+    impl Matcher<i32> for Even {
+        fn matches(&self, actual: &i32) -> MatchResult {
+            (actual % 2 == 0).into()
+        }
+
+        fn describe(&self) -> String {
+            "even".to_string()
+        }
+
+        fn describe_mismatch(&self, actual: &i32) -> Option<String> {
+            Some(format!("was {actual}"))
+        }
+    }
+
+    #[test]
+    fn should_with_custom_matcher() {
+        4.should(Even);
+
+        // A custom description is kept, even when it reads like the default.
+        let error = 7.try_should(Even).unwrap_err();
+        assert_eq!(error.to_string(), " should match even but was 7\n\nwas 7");
+    }
+
+    #[test]
+    fn should_with_has_field() {
+        let order = Order {
+            id: 4,
+            items: vec!["tea"],
+        };
+        order.should(has_field("id", |o: &Order| &o.id, eq(4)));
+
+        let error = order
+            .try_should(has_field("items", |o: &Order| &o.items, contains("milk")))
+            .unwrap_err();
+        assert_eq!(
+            error.expected.as_deref(),
+            Some("has field 'items' containing \"milk\"")
+        );
+        assert_eq!(
+            error.details.as_deref(),
+            Some("field 'items' was [\"tea\"]")
+        );
+    }
+}
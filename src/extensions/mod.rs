@@ -1,5 +1,19 @@
 pub(crate) mod base;
 pub(crate) mod eq;
+pub(crate) mod error;
+pub(crate) mod float;
+pub(crate) mod hash_iter;
 pub(crate) mod iter;
+#[cfg(feature = "json")]
+pub(crate) mod json;
+pub(crate) mod matcher;
+pub(crate) mod option;
+pub(crate) mod ordered_iter;
+pub(crate) mod panic;
+pub(crate) mod partial_eq;
 pub(crate) mod partial_ord;
+pub(crate) mod result;
+#[cfg(feature = "serde")]
+pub(crate) mod serde;
+pub(crate) mod snapshot;
 pub(crate) mod string;
@@ -0,0 +1,114 @@
+use crate::{
+    assertions::{assert_result, check_unary, unwrap_result},
+    context::AssertionContextBuilder,
+    error::AssertionError,
+    message_generator,
+};
+
+use std::fmt::Debug;
+
+pub trait ShouldBeOptionExtension<T: Debug> {
+    /// Assert that this option contains a value.
+    fn should_be_some(&self);
+
+    /// Assert that this option does not contain a value.
+    fn should_be_none(&self);
+
+    /// Assert that this option contains a value, and return it for further
+    /// assertions.
+    fn should_unwrap_some(self) -> T;
+
+    /// Non-panicking version of 'should_be_some'.
+    fn try_should_be_some(&self) -> Result<(), AssertionError>;
+
+    /// Non-panicking version of 'should_be_none'.
+    fn try_should_be_none(&self) -> Result<(), AssertionError>;
+
+    /// Non-panicking version of 'should_unwrap_some'.
+    fn try_should_unwrap_some(self) -> Result<T, AssertionError>;
+}
+
+impl<T: Debug> ShouldBeOptionExtension<T> for Option<T> {
+    fn should_be_some(&self) {
+        assert_result(self.try_should_be_some());
+    }
+
+    fn should_be_none(&self) {
+        assert_result(self.try_should_be_none());
+    }
+
+    fn should_unwrap_some(self) -> T {
+        unwrap_result(self.try_should_unwrap_some())
+    }
+
+    fn try_should_be_some(&self) -> Result<(), AssertionError> {
+        check_unary(
+            self,
+            |x| x.is_some(),
+            self,
+            AssertionContextBuilder::new().verb("should be Some"),
+            message_generator::failed_condition_message,
+        )
+    }
+
+    fn try_should_be_none(&self) -> Result<(), AssertionError> {
+        check_unary(
+            self,
+            |x| x.is_none(),
+            self,
+            AssertionContextBuilder::new().verb("should be None"),
+            message_generator::failed_condition_message,
+        )
+    }
+
+    fn try_should_unwrap_some(self) -> Result<T, AssertionError> {
+        match self {
+            Some(value) => Ok(value),
+            None => Err(message_generator::failed_condition_message(
+                None::<T>,
+                AssertionContextBuilder::new().verb("should be Some").into(),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ShouldBeEqExtension;
+
+    #[test]
+    fn should_be_some() {
+        Some(1).should_be_some();
+
+        let error = None::<i32>.try_should_be_some().unwrap_err();
+        assert_eq!(error.to_string(), " should be Some but was None");
+    }
+
+    #[test]
+    fn should_be_none() {
+        None::<i32>.should_be_none();
+
+        let error = Some(1).try_should_be_none().unwrap_err();
+        assert_eq!(error.to_string(), " should be None but was Some(1)");
+    }
+
+    #[test]
+    fn should_unwrap_some() {
+        Some(5).should_unwrap_some().should_be(&5);
+
+        let result = std::panic::catch_unwind(|| None::<i32>.should_unwrap_some());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_unwrap_some_panics_inside_soft_scope() {
+        let result = std::panic::catch_unwind(|| {
+            crate::soft(|| {
+                None::<i32>.should_unwrap_some();
+                unreachable!("a missing value cannot be continued with");
+            })
+        });
+        assert!(result.is_err());
+    }
+}
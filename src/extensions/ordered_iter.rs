@@ -0,0 +1,227 @@
+use crate::assertions::{assert_result, check_unary};
+use crate::context::AssertionContextBuilder;
+use crate::error::AssertionError;
+use crate::message_generator;
+
+use std::fmt::Debug;
+
+pub trait ShouldBeOrderedIterExtension:
+    IntoIterator<Item: PartialOrd + Debug> + Clone + Debug
+{
+    /// Assert that the generated sequence is sorted in ascending order, i.e.
+    /// each element is less than or equal to the next.
+    fn should_be_sorted(self);
+
+    /// Assert that the generated sequence is sorted in descending order, i.e.
+    /// each element is greater than or equal to the next.
+    fn should_be_reverse_sorted(self);
+
+    /// Assert that each element of the generated sequence is strictly less
+    /// than the next.
+    fn should_be_strictly_increasing(self);
+
+    /// Assert that each element of the generated sequence is strictly greater
+    /// than the next.
+    fn should_be_strictly_decreasing(self);
+
+    /// Assert that the generated sequence is sorted in ascending order of the
+    /// keys extracted by the given 'key' function.
+    fn should_be_sorted_by_key<K: PartialOrd + Debug>(self, key: impl FnMut(&Self::Item) -> K);
+
+    /// Non-panicking version of 'should_be_sorted'.
+    fn try_should_be_sorted(self) -> Result<(), AssertionError>;
+
+    /// Non-panicking version of 'should_be_reverse_sorted'.
+    fn try_should_be_reverse_sorted(self) -> Result<(), AssertionError>;
+
+    /// Non-panicking version of 'should_be_strictly_increasing'.
+    fn try_should_be_strictly_increasing(self) -> Result<(), AssertionError>;
+
+    /// Non-panicking version of 'should_be_strictly_decreasing'.
+    fn try_should_be_strictly_decreasing(self) -> Result<(), AssertionError>;
+
+    /// Non-panicking version of 'should_be_sorted_by_key'.
+    fn try_should_be_sorted_by_key<K: PartialOrd + Debug>(
+        self,
+        key: impl FnMut(&Self::Item) -> K,
+    ) -> Result<(), AssertionError>;
+}
+
+impl<T> ShouldBeOrderedIterExtension for T
+where
+    T: Iterator<Item: PartialOrd + Debug> + Clone + Debug,
+{
+    fn should_be_sorted(self) {
+        assert_result(self.try_should_be_sorted());
+    }
+
+    fn should_be_reverse_sorted(self) {
+        assert_result(self.try_should_be_reverse_sorted());
+    }
+
+    fn should_be_strictly_increasing(self) {
+        assert_result(self.try_should_be_strictly_increasing());
+    }
+
+    fn should_be_strictly_decreasing(self) {
+        assert_result(self.try_should_be_strictly_decreasing());
+    }
+
+    fn should_be_sorted_by_key<K: PartialOrd + Debug>(self, key: impl FnMut(&Self::Item) -> K) {
+        assert_result(self.try_should_be_sorted_by_key(key));
+    }
+
+    fn try_should_be_sorted(self) -> Result<(), AssertionError> {
+        check_order(self, "should be sorted", |x, y| x <= y)
+    }
+
+    fn try_should_be_reverse_sorted(self) -> Result<(), AssertionError> {
+        check_order(self, "should be sorted in reverse", |x, y| x >= y)
+    }
+
+    fn try_should_be_strictly_increasing(self) -> Result<(), AssertionError> {
+        check_order(self, "should be strictly increasing", |x, y| x < y)
+    }
+
+    fn try_should_be_strictly_decreasing(self) -> Result<(), AssertionError> {
+        check_order(self, "should be strictly decreasing", |x, y| x > y)
+    }
+
+    fn try_should_be_sorted_by_key<K: PartialOrd + Debug>(
+        self,
+        key: impl FnMut(&Self::Item) -> K,
+    ) -> Result<(), AssertionError> {
+        let cloned = self.clone();
+        let items: Vec<_> = self.clone().collect();
+        let keys: Vec<_> = items.iter().map(key).collect();
+        let violation = first_out_of_order(&keys, |x, y| x <= y);
+
+        check_unary(
+            self,
+            |_| violation.is_none(),
+            cloned,
+            AssertionContextBuilder::new().verb("should be sorted by the given key"),
+            |actual, context| {
+                let mut error = message_generator::failed_condition_message(actual, context);
+                error.details = violation.map(|index| {
+                    format!(
+                        "Out of order at indices {index} and {}: {:?} (key {:?}) then {:?} (key {:?})",
+                        index + 1,
+                        items[index],
+                        keys[index],
+                        items[index + 1],
+                        keys[index + 1]
+                    )
+                });
+                error
+            },
+        )
+    }
+}
+
+/// Check that every adjacent pair of elements generated by 'iter' is
+/// 'in_order', reporting the first pair which is not.
+fn check_order<I>(
+    iter: I,
+    verb: &str,
+    in_order: impl Fn(&I::Item, &I::Item) -> bool,
+) -> Result<(), AssertionError>
+where
+    I: Iterator<Item: PartialOrd + Debug> + Clone + Debug,
+{
+    let cloned = iter.clone();
+    let items: Vec<_> = iter.clone().collect();
+    let violation = first_out_of_order(&items, in_order);
+
+    check_unary(
+        iter,
+        |_| violation.is_none(),
+        cloned,
+        AssertionContextBuilder::new().verb(verb),
+        |actual, context| {
+            let mut error = message_generator::failed_condition_message(actual, context);
+            error.details = violation.map(|index| {
+                format!(
+                    "Out of order at indices {index} and {}: {:?} then {:?}",
+                    index + 1,
+                    items[index],
+                    items[index + 1]
+                )
+            });
+            error
+        },
+    )
+}
+
+fn first_out_of_order<T>(items: &[T], in_order: impl Fn(&T, &T) -> bool) -> Option<usize> {
+    items
+        .windows(2)
+        .position(|pair| !in_order(&pair[0], &pair[1]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_be_sorted() {
+        [1, 2, 2, 3].iter().should_be_sorted();
+        std::iter::empty::<i32>().should_be_sorted();
+
+        let error = [1, 3, 2, 4].iter().try_should_be_sorted().unwrap_err();
+        assert_eq!(error.verb, "should be sorted");
+        assert_eq!(
+            error.details.as_deref(),
+            Some("Out of order at indices 1 and 2: 3 then 2")
+        );
+
+        let result = std::panic::catch_unwind(|| [2.0, f64::NAN].iter().should_be_sorted());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_should_be_reverse_sorted() {
+        [3, 2, 2, 1].iter().should_be_reverse_sorted();
+
+        let result = std::panic::catch_unwind(|| (0..3).should_be_reverse_sorted());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_should_be_strictly_increasing() {
+        [0.5, 1.0, 1.5].iter().should_be_strictly_increasing();
+
+        let error = [1, 2, 2]
+            .iter()
+            .try_should_be_strictly_increasing()
+            .unwrap_err();
+        assert_eq!(
+            error.details.as_deref(),
+            Some("Out of order at indices 1 and 2: 2 then 2")
+        );
+    }
+
+    #[test]
+    fn test_should_be_strictly_decreasing() {
+        (0..3).rev().should_be_strictly_decreasing();
+
+        let result = std::panic::catch_unwind(|| [3, 3].iter().should_be_strictly_decreasing());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_should_be_sorted_by_key() {
+        ["a", "bb", "ccc"]
+            .iter()
+            .should_be_sorted_by_key(|x| x.len());
+
+        let error = ["a", "ccc", "bb"]
+            .iter()
+            .try_should_be_sorted_by_key(|x| x.len())
+            .unwrap_err();
+        assert_eq!(
+            error.details.as_deref(),
+            Some("Out of order at indices 1 and 2: \"ccc\" (key 3) then \"bb\" (key 2)")
+        );
+    }
+}
@@ -0,0 +1,269 @@
+use crate::assertions::{assert_result, check_unary, install_hook, unwrap_result};
+use crate::context::AssertionContextBuilder;
+use crate::error::{AssertionError, AssertionFailure};
+use crate::message_generator;
+use crate::panic::{payload_as_str, silenced};
+use crate::soft;
+
+use std::any::{type_name, Any};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+type Payload = Box<dyn Any + Send>;
+
+const RETURNED: &str = "completed without panicking";
+
+pub trait ShouldPanicExtension<R>: FnOnce() -> R + Sized {
+    /// Assert that calling this closure panics.
+    fn should_panic(self);
+
+    /// Assert that calling this closure panics with a message containing the
+    /// given 'expected'.
+    fn should_panic_with_message(self, expected: &str);
+
+    /// Assert that calling this closure panics with a payload of type 'P',
+    /// and return the payload for further assertions.
+    fn should_panic_with_payload<P: Any>(self) -> P;
+
+    /// Assert that calling this closure fails an assertion made via this
+    /// library, and return the failure for further assertions, e.g. to test
+    /// the messages of custom assertion helpers.
+    fn should_fail_with_assertion(self) -> AssertionFailure;
+
+    /// Assert that calling this closure does not panic, and return its result
+    /// for further assertions.
+    fn should_not_panic(self) -> R;
+
+    /// Non-panicking version of 'should_panic'.
+    fn try_should_panic(self) -> Result<(), AssertionError>;
+
+    /// Non-panicking version of 'should_panic_with_message'.
+    fn try_should_panic_with_message(self, expected: &str) -> Result<(), AssertionError>;
+
+    /// Non-panicking version of 'should_panic_with_payload'.
+    fn try_should_panic_with_payload<P: Any>(self) -> Result<P, AssertionError>;
+
+    /// Non-panicking version of 'should_fail_with_assertion'.
+    fn try_should_fail_with_assertion(self) -> Result<AssertionFailure, AssertionError>;
+
+    /// Non-panicking version of 'should_not_panic'.
+    fn try_should_not_panic(self) -> Result<R, AssertionError>;
+}
+
+impl<F: FnOnce() -> R, R> ShouldPanicExtension<R> for F {
+    fn should_panic(self) {
+        assert_result(self.try_should_panic());
+    }
+
+    fn should_panic_with_message(self, expected: &str) {
+        assert_result(self.try_should_panic_with_message(expected));
+    }
+
+    fn should_panic_with_payload<P: Any>(self) -> P {
+        unwrap_result(self.try_should_panic_with_payload())
+    }
+
+    fn should_fail_with_assertion(self) -> AssertionFailure {
+        unwrap_result(self.try_should_fail_with_assertion())
+    }
+
+    fn should_not_panic(self) -> R {
+        unwrap_result(self.try_should_not_panic())
+    }
+
+    fn try_should_panic(self) -> Result<(), AssertionError> {
+        let panicked = run(self, true).is_err();
+
+        check_unary(
+            RETURNED,
+            |_| panicked,
+            RETURNED,
+            AssertionContextBuilder::new()
+                .verb("should have panicked")
+                .actual_mapper(Box::new(|x| format!(" {x}"))),
+            message_generator::failed_condition_message,
+        )
+    }
+
+    fn try_should_panic_with_message(self, expected: &str) -> Result<(), AssertionError> {
+        let (message, passed) = match run(self, true) {
+            Ok(_) => (RETURNED.to_string(), false),
+            Err(payload) => {
+                let message = payload_message(&payload);
+                let passed = message.contains(expected);
+                (format!("{message:?}"), passed)
+            }
+        };
+
+        check_unary(
+            message.as_str(),
+            |_| passed,
+            message.as_str(),
+            AssertionContextBuilder::new()
+                .verb(&format!(
+                    "should have panicked with a message containing {expected:?}"
+                ))
+                .actual_mapper(Box::new(|x| format!(" {x}"))),
+            message_generator::failed_condition_message,
+        )
+    }
+
+    fn try_should_panic_with_payload<P: Any>(self) -> Result<P, AssertionError> {
+        catch_payload(
+            self,
+            &format!(
+                "should have panicked with a payload of type {}",
+                type_name::<P>()
+            ),
+        )
+    }
+
+    fn try_should_fail_with_assertion(self) -> Result<AssertionFailure, AssertionError> {
+        catch_payload(self, "should have failed an assertion")
+    }
+
+    fn try_should_not_panic(self) -> Result<R, AssertionError> {
+        let payload = match run(self, false) {
+            Ok(value) => return Ok(value),
+            Err(payload) => payload,
+        };
+
+        Err(message_generator::failed_condition_message(
+            payload_message(&payload),
+            AssertionContextBuilder::new()
+                .verb("should not have panicked")
+                .actual_mapper(Box::new(|x| format!(" panicking with {x:?}")))
+                .into(),
+        ))
+    }
+}
+
+/// Call 'f', expecting it to panic with a payload of type 'P'.
+fn catch_payload<P: Any, R>(f: impl FnOnce() -> R, verb: &str) -> Result<P, AssertionError> {
+    let actual = match run(f, true) {
+        Ok(_) => RETURNED.to_string(),
+        Err(payload) => match payload.downcast::<P>() {
+            Ok(payload) => return Ok(*payload),
+            Err(payload) => format!("{:?}", payload_message(&payload)),
+        },
+    };
+
+    Err(message_generator::failed_condition_message(
+        actual,
+        AssertionContextBuilder::new()
+            .verb(verb)
+            .actual_mapper(Box::new(|x| format!(" {x}")))
+            .into(),
+    ))
+}
+
+/// Call 'f', catching any panic it raises. Its assertions panic even inside a
+/// soft scope, and if the panic is 'expected' it is not reported by the hook.
+fn run<R>(f: impl FnOnce() -> R, expected: bool) -> Result<R, Payload> {
+    let call = || soft::suspended(|| catch_unwind(AssertUnwindSafe(f)));
+
+    if expected {
+        install_hook();
+        silenced(call)
+    } else {
+        call()
+    }
+}
+
+fn payload_message(payload: &Payload) -> String {
+    payload_as_str(payload.as_ref()).trim_start().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ShouldBeEqExtension, ShouldBeStringExtension};
+
+    #[test]
+    fn should_panic() {
+        (|| panic!("boom")).should_panic();
+        (|| 1.should_be(&2)).should_panic();
+
+        let error = (|| 1).try_should_panic().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            " should have panicked but was completed without panicking"
+        );
+    }
+
+    #[test]
+    fn should_panic_with_message() {
+        (|| panic!("index {} out of range", 3)).should_panic_with_message("3 out of range");
+        (|| 1.should_be(&2)).should_panic_with_message("should be 2 but was 1");
+
+        let error = (|| panic!("boom"))
+            .try_should_panic_with_message("bang")
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            " should have panicked with a message containing \"bang\" but was \"boom\""
+        );
+    }
+
+    #[test]
+    fn should_panic_with_payload() {
+        (|| std::panic::panic_any(42u8))
+            .should_panic_with_payload::<u8>()
+            .should_be(&42);
+
+        let failure = (|| 1.should_be(&2)).should_panic_with_payload::<crate::AssertionFailure>();
+        failure.verb.should_be(&"should be".to_string());
+
+        let error = (|| panic!("boom"))
+            .try_should_panic_with_payload::<u8>()
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            " should have panicked with a payload of type u8 but was \"boom\""
+        );
+    }
+
+    #[test]
+    fn should_fail_with_assertion() {
+        let failure = (|| {
+            let value = 1;
+            value.should_be(&2)
+        })
+        .should_fail_with_assertion();
+        failure.verb.should_be(&"should be".to_string());
+        failure.expected.should_be(&Some("2".to_string()));
+        failure.actual.should_be(&"1".to_string());
+        failure.custom_message.should_be(&None);
+        failure
+            .asserted_expression
+            .should_be(&Some("value".to_string()));
+
+        let error = (|| panic!("boom"))
+            .try_should_fail_with_assertion()
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            " should have failed an assertion but was \"boom\""
+        );
+
+        let result = std::panic::catch_unwind(|| (|| 1).should_fail_with_assertion());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_not_panic() {
+        (|| "value").should_not_panic().should_contain("val");
+
+        let error = (|| panic!("boom")).try_should_not_panic().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            " should not have panicked but was panicking with \"boom\""
+        );
+    }
+
+    #[test]
+    fn assertions_in_closure_panic_inside_soft_scope() {
+        crate::soft(|| {
+            (|| 1.should_be(&2)).should_panic();
+        });
+    }
+}
@@ -0,0 +1,113 @@
+use crate::{
+    assertions::{assert_result, check_comparison},
+    context::AssertionContextBuilder,
+    error::AssertionError,
+    message_generator,
+};
+
+use std::fmt::Debug;
+
+pub trait ShouldEqualExtension<Rhs: ?Sized + Debug = Self>: PartialEq<Rhs> + Debug {
+    /// Assert that this object is equal to the given 'expected', which may be
+    /// of any type this object can be compared to, e.g. a 'String' to a 'str'.
+    fn should_equal(&self, expected: &Rhs);
+
+    /// Assert that this object is not equal to the given 'expected'.
+    fn should_not_equal(&self, expected: &Rhs);
+
+    /// Non-panicking version of 'should_equal'.
+    fn try_should_equal(&self, expected: &Rhs) -> Result<(), AssertionError>;
+
+    /// Non-panicking version of 'should_not_equal'.
+    fn try_should_not_equal(&self, expected: &Rhs) -> Result<(), AssertionError>;
+}
+
+impl<T, Rhs> ShouldEqualExtension<Rhs> for T
+where
+    T: ?Sized + PartialEq<Rhs> + Debug,
+    Rhs: ?Sized + Debug,
+{
+    fn should_equal(&self, expected: &Rhs) {
+        assert_result(self.try_should_equal(expected));
+    }
+
+    fn should_not_equal(&self, expected: &Rhs) {
+        assert_result(self.try_should_not_equal(expected));
+    }
+
+    fn try_should_equal(&self, expected: &Rhs) -> Result<(), AssertionError> {
+        check_comparison(
+            self,
+            |x| x == expected,
+            self,
+            expected,
+            AssertionContextBuilder::new().verb("should equal"),
+            message_generator::expected_vs_actual_diff_message,
+        )
+    }
+
+    fn try_should_not_equal(&self, expected: &Rhs) -> Result<(), AssertionError> {
+        check_comparison(
+            self,
+            |x| x != expected,
+            self,
+            expected,
+            AssertionContextBuilder::new()
+                .verb("should not equal")
+                .actual_mapper(Box::new(|_| "".to_string())),
+            message_generator::expected_vs_actual_message,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Measurement {
+        label: String,
+        value: f64,
+    }
+
+    #[test]
+    fn should_equal_partial_eq_only() {
+        let measurement = Measurement {
+            label: "height".to_string(),
+            value: 1.5,
+        };
+
+        measurement.should_equal(&Measurement {
+            label: "height".to_string(),
+            value: 1.5,
+        });
+        1.5f64.should_equal(&1.5);
+
+        let result = std::panic::catch_unwind(|| f64::NAN.should_equal(&f64::NAN));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_equal_heterogeneous() {
+        "abc".to_string().should_equal("abc");
+        "abc".should_equal(&"abc".to_string());
+        vec![1, 2, 3].should_equal(&[1, 2, 3]);
+        vec![1, 2, 3].should_equal(&[1, 2, 3][..]);
+
+        let error = "abc".to_string().try_should_equal("abd").unwrap_err();
+        assert_eq!(error.to_string(), " should equal \"abd\" but was \"abc\"");
+
+        let error = vec![1, 2].try_should_equal(&[1, 2, 3][..]).unwrap_err();
+        assert_eq!(error.expected.as_deref(), Some("[1, 2, 3]"));
+        assert_eq!(error.actual, "[1, 2]");
+    }
+
+    #[test]
+    fn should_not_equal() {
+        "abc".to_string().should_not_equal("abd");
+        1.5f64.should_not_equal(&2.5);
+
+        let result = std::panic::catch_unwind(|| "abc".to_string().should_not_equal("abc"));
+        assert!(result.is_err());
+    }
+}
@@ -1,12 +1,96 @@
-use crate::{assertions::assert_comparison, context::AssertionContextBuilder, message_generator};
+use crate::{
+    assertions::{assert_result, check_comparison},
+    context::AssertionContextBuilder,
+    error::AssertionError,
+    message_generator,
+};
 
+use std::cmp::Ordering;
 use std::fmt::Debug;
+use std::ops::{Bound, RangeBounds, RangeInclusive};
+
+/// Subtraction which reports overflow instead of panicking, used by
+/// 'should_be_within' to tell by how much a bound was violated.
+pub trait CheckedSub: Sized {
+    /// Returns 'self - other', or 'None' if the result is not representable.
+    fn checked_sub(&self, other: &Self) -> Option<Self>;
+}
+
+macro_rules! impl_checked_sub_for_integers {
+    ($($t:ty),*) => {$(
+        impl CheckedSub for $t {
+            fn checked_sub(&self, other: &Self) -> Option<Self> {
+                <$t>::checked_sub(*self, *other)
+            }
+        }
+    )*};
+}
+
+impl_checked_sub_for_integers!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl CheckedSub for f32 {
+    fn checked_sub(&self, other: &Self) -> Option<Self> {
+        Some(self - other)
+    }
+}
+
+impl CheckedSub for f64 {
+    fn checked_sub(&self, other: &Self) -> Option<Self> {
+        Some(self - other)
+    }
+}
 
 pub trait ShouldBePartialOrdExtension: PartialOrd + Debug {
     fn should_be_lt(&self, expected: &Self);
     fn should_be_le(&self, expected: &Self);
     fn should_be_gt(&self, expected: &Self);
     fn should_be_ge(&self, expected: &Self);
+
+    /// Assert that this object lies within the given 'range', which may be
+    /// inclusive, exclusive or unbounded on either side.
+    fn should_be_in(&self, range: impl RangeBounds<Self> + Debug);
+
+    /// Like 'should_be_in', but also reporting by how much a bound was
+    /// violated, for types which can be subtracted.
+    fn should_be_within(&self, range: impl RangeBounds<Self> + Debug)
+    where
+        Self: CheckedSub + Sized;
+
+    /// Assert that this object lies outside of the given 'range'.
+    fn should_not_be_in(&self, range: impl RangeBounds<Self> + Debug);
+
+    /// Assert that this object lies between 'low' and 'high', inclusive.
+    fn should_be_between(&self, low: Self, high: Self)
+    where
+        Self: Sized;
+
+    /// Non-panicking version of 'should_be_lt'.
+    fn try_should_be_lt(&self, expected: &Self) -> Result<(), AssertionError>;
+    /// Non-panicking version of 'should_be_le'.
+    fn try_should_be_le(&self, expected: &Self) -> Result<(), AssertionError>;
+    /// Non-panicking version of 'should_be_gt'.
+    fn try_should_be_gt(&self, expected: &Self) -> Result<(), AssertionError>;
+    /// Non-panicking version of 'should_be_ge'.
+    fn try_should_be_ge(&self, expected: &Self) -> Result<(), AssertionError>;
+    /// Non-panicking version of 'should_be_in'.
+    fn try_should_be_in(&self, range: impl RangeBounds<Self> + Debug)
+        -> Result<(), AssertionError>;
+    /// Non-panicking version of 'should_be_within'.
+    fn try_should_be_within(
+        &self,
+        range: impl RangeBounds<Self> + Debug,
+    ) -> Result<(), AssertionError>
+    where
+        Self: CheckedSub + Sized;
+    /// Non-panicking version of 'should_not_be_in'.
+    fn try_should_not_be_in(
+        &self,
+        range: impl RangeBounds<Self> + Debug,
+    ) -> Result<(), AssertionError>;
+    /// Non-panicking version of 'should_be_between'.
+    fn try_should_be_between(&self, low: Self, high: Self) -> Result<(), AssertionError>
+    where
+        Self: Sized;
 }
 
 impl<T> ShouldBePartialOrdExtension for T
@@ -14,48 +98,219 @@ where
     T: PartialOrd + Debug,
 {
     fn should_be_lt(&self, expected: &Self) {
-        assert_comparison(
+        assert_result(self.try_should_be_lt(expected));
+    }
+
+    fn should_be_le(&self, expected: &Self) {
+        assert_result(self.try_should_be_le(expected));
+    }
+
+    fn should_be_gt(&self, expected: &Self) {
+        assert_result(self.try_should_be_gt(expected));
+    }
+
+    fn should_be_ge(&self, expected: &Self) {
+        assert_result(self.try_should_be_ge(expected));
+    }
+
+    fn should_be_in(&self, range: impl RangeBounds<Self> + Debug) {
+        assert_result(self.try_should_be_in(range));
+    }
+
+    fn should_be_within(&self, range: impl RangeBounds<Self> + Debug)
+    where
+        Self: CheckedSub,
+    {
+        assert_result(self.try_should_be_within(range));
+    }
+
+    fn should_not_be_in(&self, range: impl RangeBounds<Self> + Debug) {
+        assert_result(self.try_should_not_be_in(range));
+    }
+
+    fn should_be_between(&self, low: Self, high: Self) {
+        assert_result(self.try_should_be_between(low, high));
+    }
+
+    fn try_should_be_lt(&self, expected: &Self) -> Result<(), AssertionError> {
+        check_comparison(
             self,
             |x| x < expected,
             self,
             expected,
             AssertionContextBuilder::new().verb("should be less than"),
             message_generator::expected_vs_actual_message,
-        );
+        )
     }
 
-    fn should_be_le(&self, expected: &Self) {
-        assert_comparison(
+    fn try_should_be_le(&self, expected: &Self) -> Result<(), AssertionError> {
+        check_comparison(
             self,
             |x| x <= expected,
-            &self,
+            self,
             expected,
             AssertionContextBuilder::new().verb("should be less than or equal to"),
             message_generator::expected_vs_actual_message,
-        );
+        )
     }
 
-    fn should_be_gt(&self, expected: &Self) {
-        assert_comparison(
+    fn try_should_be_gt(&self, expected: &Self) -> Result<(), AssertionError> {
+        check_comparison(
             self,
             |x| x > expected,
-            &self,
+            self,
             expected,
             AssertionContextBuilder::new().verb("should be greater than"),
             message_generator::expected_vs_actual_message,
-        );
+        )
     }
 
-    fn should_be_ge(&self, expected: &Self) {
-        assert_comparison(
+    fn try_should_be_ge(&self, expected: &Self) -> Result<(), AssertionError> {
+        check_comparison(
             self,
             |x| x >= expected,
-            &self,
+            self,
             expected,
             AssertionContextBuilder::new().verb("should be greater than or equal to"),
             message_generator::expected_vs_actual_message,
-        );
+        )
+    }
+
+    fn try_should_be_in(
+        &self,
+        range: impl RangeBounds<Self> + Debug,
+    ) -> Result<(), AssertionError> {
+        let violation = violated_bound(self, &range, |_, _| None);
+        let passed = violation.is_none();
+
+        check_comparison(
+            self,
+            |_| passed,
+            self,
+            range,
+            AssertionContextBuilder::new().verb("should be in"),
+            |actual, expected, context| {
+                let mut error =
+                    message_generator::expected_vs_actual_message(actual, expected, context);
+                error.details = violation;
+                error
+            },
+        )
+    }
+
+    fn try_should_be_within(
+        &self,
+        range: impl RangeBounds<Self> + Debug,
+    ) -> Result<(), AssertionError>
+    where
+        Self: CheckedSub,
+    {
+        let violation = violated_bound(self, &range, |value, bound| {
+            // Subtract the smaller side, so that unsigned types cannot underflow.
+            let distance = if value > bound {
+                value.checked_sub(bound)
+            } else {
+                bound.checked_sub(value)
+            };
+            distance.map(|distance| format!("{distance:?}"))
+        });
+        let passed = violation.is_none();
+
+        check_comparison(
+            self,
+            |_| passed,
+            self,
+            range,
+            AssertionContextBuilder::new().verb("should be in"),
+            |actual, expected, context| {
+                let mut error =
+                    message_generator::expected_vs_actual_message(actual, expected, context);
+                error.details = violation;
+                error
+            },
+        )
+    }
+
+    fn try_should_not_be_in(
+        &self,
+        range: impl RangeBounds<Self> + Debug,
+    ) -> Result<(), AssertionError> {
+        let passed = violated_bound(self, &range, |_, _| None).is_some();
+
+        check_comparison(
+            self,
+            |_| passed,
+            self,
+            range,
+            AssertionContextBuilder::new().verb("should not be in"),
+            message_generator::expected_vs_actual_message,
+        )
+    }
+
+    fn try_should_be_between(&self, low: Self, high: Self) -> Result<(), AssertionError> {
+        let range: RangeInclusive<Self> = low..=high;
+        let violation = violated_bound(self, &range, |_, _| None);
+        let passed = violation.is_none();
+
+        check_comparison(
+            self,
+            |_| passed,
+            self,
+            range,
+            AssertionContextBuilder::new().verb("should be between"),
+            |actual, expected, context| {
+                let mut error =
+                    message_generator::expected_vs_actual_message(actual, expected, context);
+                error.details = violation;
+                error
+            },
+        )
+    }
+}
+
+/// Describe which bound of 'range' the given 'value' violates, if any, and
+/// by how much when 'distance' can tell.
+fn violated_bound<T: PartialOrd + Debug + ?Sized>(
+    value: &T,
+    range: &impl RangeBounds<T>,
+    distance: impl Fn(&T, &T) -> Option<String>,
+) -> Option<String> {
+    let bounds = [
+        ("lower", range.start_bound(), Ordering::Greater),
+        ("upper", range.end_bound(), Ordering::Less),
+    ];
+
+    for (side, bound, inside) in bounds {
+        let (bound, inclusive) = match bound {
+            Bound::Included(bound) => (bound, true),
+            Bound::Excluded(bound) => (bound, false),
+            Bound::Unbounded => continue,
+        };
+        let kind = if inclusive { "inclusive" } else { "exclusive" };
+
+        match value.partial_cmp(bound) {
+            None => {
+                return Some(format!(
+                    "Could not be compared to the {side} bound {bound:?} ({kind})"
+                ))
+            }
+            Some(ordering) if ordering == inside => {}
+            Some(Ordering::Equal) if inclusive => {}
+            // Equal to an exclusive bound, so there is no distance to report.
+            Some(Ordering::Equal) => {
+                return Some(format!("Violated the {side} bound {bound:?} ({kind})"));
+            }
+            Some(_) => {
+                let by = match distance(value, bound) {
+                    Some(distance) => format!(" by {distance}"),
+                    None => "".to_string(),
+                };
+                return Some(format!("Violated the {side} bound {bound:?} ({kind}){by}"));
+            }
+        }
     }
+
+    None
 }
 
 #[cfg(test)]
@@ -82,4 +337,113 @@ mod tests {
         let result = std::panic::catch_unwind(|| 1f32.should_be_ge(&2f32));
         assert!(result.is_err());
     }
+
+    #[test]
+    fn try_comparisons() {
+        1.try_should_be_lt(&2).unwrap();
+        2.try_should_be_ge(&2).unwrap();
+
+        let error = 1f32.try_should_be_ge(&2f32).unwrap_err();
+        assert_eq!(error.verb, "should be greater than or equal to");
+        assert_eq!(error.expected.as_deref(), Some("2.0"));
+        assert_eq!(error.actual, "1.0");
+    }
+
+    #[test]
+    fn should_be_in() {
+        5.should_be_in(1..10);
+        5.should_be_in(5..=5);
+        5.should_be_in(..6);
+        5.should_be_in(5..);
+        "m".should_be_in("a".."z");
+
+        let error = 12.try_should_be_in(1..10).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            " should be in 1..10 but was 12\n\nViolated the upper bound 10 (exclusive)"
+        );
+
+        let error = f64::NAN.try_should_be_in(1.0..=2.0).unwrap_err();
+        assert_eq!(
+            error.details.as_deref(),
+            Some("Could not be compared to the lower bound 1.0 (inclusive)")
+        );
+
+        let error = "zz".try_should_be_in("a".."z").unwrap_err();
+        assert_eq!(
+            error.details.as_deref(),
+            Some("Violated the upper bound \"z\" (exclusive)")
+        );
+    }
+
+    #[test]
+    fn should_be_within() {
+        5u8.should_be_within(1..10);
+
+        let error = 12.try_should_be_within(1..10).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            " should be in 1..10 but was 12\n\nViolated the upper bound 10 (exclusive) by 2"
+        );
+
+        let error = 0u8.try_should_be_within(1..=5).unwrap_err();
+        assert_eq!(
+            error.details.as_deref(),
+            Some("Violated the lower bound 1 (inclusive) by 1")
+        );
+
+        let error = 10.try_should_be_within(1..10).unwrap_err();
+        assert_eq!(
+            error.details.as_deref(),
+            Some("Violated the upper bound 10 (exclusive)")
+        );
+
+        let error = 0.5f64.try_should_be_within(1.0..=2.0).unwrap_err();
+        assert_eq!(
+            error.details.as_deref(),
+            Some("Violated the lower bound 1.0 (inclusive) by 0.5")
+        );
+    }
+
+    #[test]
+    fn should_be_within_signed_extremes() {
+        let error = (-100i8).try_should_be_within(100i8..).unwrap_err();
+        assert_eq!(
+            error.details.as_deref(),
+            Some("Violated the lower bound 100 (inclusive)")
+        );
+
+        let error = i32::MIN.try_should_be_within(0..).unwrap_err();
+        assert_eq!(
+            error.details.as_deref(),
+            Some("Violated the lower bound 0 (inclusive)")
+        );
+
+        let error = i64::MAX.try_should_be_within(..-1).unwrap_err();
+        assert_eq!(
+            error.details.as_deref(),
+            Some("Violated the upper bound -1 (exclusive)")
+        );
+    }
+
+    #[test]
+    fn should_not_be_in() {
+        12.should_not_be_in(1..10);
+        10.should_not_be_in(1..10);
+
+        let result = std::panic::catch_unwind(|| 5.should_not_be_in(1..=5));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_be_between() {
+        5.should_be_between(1, 5);
+        'c'.should_be_between('a', 'z');
+
+        let error = 0u8.try_should_be_between(1, 5).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            " should be between 1..=5 but was 0\n\nViolated the lower bound 1 (inclusive)"
+        );
+    }
 }
@@ -0,0 +1,251 @@
+use crate::{
+    assertions::{assert_result, check_unary, unwrap_result},
+    context::AssertionContextBuilder,
+    error::AssertionError,
+    extensions::error::{chain_messages, render_chain},
+    message_generator,
+};
+
+use std::error::Error;
+use std::fmt::Debug;
+
+pub trait ShouldBeResultExtension<T: Debug, E: Debug> {
+    /// Assert that this result is 'Ok'.
+    fn should_be_ok(&self);
+
+    /// Assert that this result is 'Err'.
+    fn should_be_err(&self);
+
+    /// Assert that this result is 'Ok', and return its value for further
+    /// assertions.
+    fn should_unwrap_ok(self) -> T;
+
+    /// Assert that this result is 'Err', and return its error for further
+    /// assertions.
+    fn should_unwrap_err(self) -> E;
+
+    /// Like 'should_be_ok', but also reporting the message and source chain
+    /// of an unexpected error.
+    fn should_be_ok_with_chain(&self)
+    where
+        E: Error;
+
+    /// Like 'should_unwrap_ok', but also reporting the message and source
+    /// chain of an unexpected error.
+    fn should_unwrap_ok_with_chain(self) -> T
+    where
+        E: Error;
+
+    /// Non-panicking version of 'should_be_ok'.
+    fn try_should_be_ok(&self) -> Result<(), AssertionError>;
+
+    /// Non-panicking version of 'should_be_err'.
+    fn try_should_be_err(&self) -> Result<(), AssertionError>;
+
+    /// Non-panicking version of 'should_unwrap_ok'.
+    fn try_should_unwrap_ok(self) -> Result<T, AssertionError>;
+
+    /// Non-panicking version of 'should_unwrap_err'.
+    fn try_should_unwrap_err(self) -> Result<E, AssertionError>;
+
+    /// Non-panicking version of 'should_be_ok_with_chain'.
+    fn try_should_be_ok_with_chain(&self) -> Result<(), AssertionError>
+    where
+        E: Error;
+
+    /// Non-panicking version of 'should_unwrap_ok_with_chain'.
+    fn try_should_unwrap_ok_with_chain(self) -> Result<T, AssertionError>
+    where
+        E: Error;
+}
+
+impl<T: Debug, E: Debug> ShouldBeResultExtension<T, E> for Result<T, E> {
+    fn should_be_ok(&self) {
+        assert_result(self.try_should_be_ok());
+    }
+
+    fn should_be_err(&self) {
+        assert_result(self.try_should_be_err());
+    }
+
+    fn should_unwrap_ok(self) -> T {
+        unwrap_result(self.try_should_unwrap_ok())
+    }
+
+    fn should_unwrap_err(self) -> E {
+        unwrap_result(self.try_should_unwrap_err())
+    }
+
+    fn should_be_ok_with_chain(&self)
+    where
+        E: Error,
+    {
+        assert_result(self.try_should_be_ok_with_chain());
+    }
+
+    fn should_unwrap_ok_with_chain(self) -> T
+    where
+        E: Error,
+    {
+        unwrap_result(self.try_should_unwrap_ok_with_chain())
+    }
+
+    fn try_should_be_ok(&self) -> Result<(), AssertionError> {
+        check_unary(
+            self,
+            |x| x.is_ok(),
+            self,
+            AssertionContextBuilder::new().verb("should be Ok"),
+            message_generator::failed_condition_message,
+        )
+    }
+
+    fn try_should_be_err(&self) -> Result<(), AssertionError> {
+        check_unary(
+            self,
+            |x| x.is_err(),
+            self,
+            AssertionContextBuilder::new().verb("should be Err"),
+            message_generator::failed_condition_message,
+        )
+    }
+
+    fn try_should_unwrap_ok(self) -> Result<T, AssertionError> {
+        match self {
+            Ok(value) => Ok(value),
+            Err(error) => Err(message_generator::failed_condition_message(
+                Err::<T, E>(error),
+                AssertionContextBuilder::new().verb("should be Ok").into(),
+            )),
+        }
+    }
+
+    fn try_should_unwrap_err(self) -> Result<E, AssertionError> {
+        match self {
+            Err(error) => Ok(error),
+            Ok(value) => Err(message_generator::failed_condition_message(
+                Ok::<T, E>(value),
+                AssertionContextBuilder::new().verb("should be Err").into(),
+            )),
+        }
+    }
+
+    fn try_should_be_ok_with_chain(&self) -> Result<(), AssertionError>
+    where
+        E: Error,
+    {
+        self.try_should_be_ok().map_err(|mut error| {
+            if let Err(cause) = self {
+                error.details = Some(render_chain(&chain_messages(cause)));
+            }
+            error
+        })
+    }
+
+    fn try_should_unwrap_ok_with_chain(self) -> Result<T, AssertionError>
+    where
+        E: Error,
+    {
+        let details = self
+            .as_ref()
+            .err()
+            .map(|cause| render_chain(&chain_messages(cause)));
+
+        self.try_should_unwrap_ok().map_err(|mut error| {
+            error.details = details;
+            error
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ShouldBeEqExtension, ShouldBeStringExtension};
+
+    #[test]
+    fn should_be_ok() {
+        Ok::<i32, String>(1).should_be_ok();
+
+        let error = Err::<i32, _>("boom".to_string())
+            .try_should_be_ok()
+            .unwrap_err();
+        assert_eq!(error.to_string(), " should be Ok but was Err(\"boom\")");
+    }
+
+    #[test]
+    fn should_be_err() {
+        Err::<i32, _>("boom").should_be_err();
+
+        let error = Ok::<i32, &str>(1).try_should_be_err().unwrap_err();
+        assert_eq!(error.to_string(), " should be Err but was Ok(1)");
+    }
+
+    #[test]
+    fn should_unwrap_ok() {
+        "42".parse::<i32>().should_unwrap_ok().should_be(&42);
+
+        let error = "forty-two"
+            .parse::<i32>()
+            .try_should_unwrap_ok()
+            .unwrap_err();
+        assert_eq!(error.verb, "should be Ok");
+        assert_eq!(error.actual, "Err(ParseIntError { kind: InvalidDigit })");
+    }
+
+    #[test]
+    fn should_unwrap_err() {
+        "forty-two"
+            .parse::<i32>()
+            .should_unwrap_err()
+            .to_string()
+            .should_contain("invalid digit");
+
+        let result = std::panic::catch_unwind(|| Ok::<i32, String>(1).should_unwrap_err());
+        assert!(result.is_err());
+    }
+
+    #[derive(Debug)]
+    struct ConfigError(std::num::ParseIntError);
+
+    impl std::fmt::Display for ConfigError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "invalid config")
+        }
+    }
+
+    impl Error for ConfigError {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    fn load(retries: &str) -> Result<i32, ConfigError> {
+        retries.parse().map_err(ConfigError)
+    }
+
+    #[test]
+    fn should_be_ok_with_chain() {
+        load("3").should_be_ok_with_chain();
+
+        let error = load("three").try_should_be_ok_with_chain().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            " should be Ok but was Err(ConfigError(ParseIntError { kind: InvalidDigit }))\n\n\
+             Error chain:\n    \
+             0: 'invalid config'\n    \
+             1: 'invalid digit found in string'"
+        );
+    }
+
+    #[test]
+    fn should_unwrap_ok_with_chain() {
+        load("3").should_unwrap_ok_with_chain().should_be(&3);
+
+        let error = load("three").try_should_unwrap_ok_with_chain().unwrap_err();
+        assert_eq!(
+            error.details.as_deref(),
+            Some("Error chain:\n    0: 'invalid config'\n    1: 'invalid digit found in string'")
+        );
+    }
+}
@@ -0,0 +1,528 @@
+use crate::assertions::{assert_result, check_comparison};
+use crate::context::AssertionContextBuilder;
+use crate::error::AssertionError;
+use crate::extensions::json::JsonOptions;
+use crate::message_generator;
+use crate::tree::{compare, describe_mismatches, Number, Tree};
+
+use serde::ser::{self, Serialize, Serializer};
+use std::fmt::{Display, Formatter};
+
+pub trait ShouldBeSerdeExtension: Serialize {
+    /// Assert that this value serializes to the same structure as the given
+    /// 'expected', reporting each differing field by its path. Neither value
+    /// needs to implement 'Debug', nor do they need to be of the same type.
+    ///
+    /// Unlike JSON, map keys may be of any type, integers keep their full
+    /// 128-bit range, and floats are compared by value, so NaN never matches.
+    fn should_be_serde_eq<E: Serialize + ?Sized>(&self, expected: &E);
+
+    /// Like 'should_be_serde_eq', but comparing as configured by 'options'.
+    fn should_be_serde_eq_with<E: Serialize + ?Sized>(&self, expected: &E, options: &JsonOptions);
+
+    /// Non-panicking version of 'should_be_serde_eq'.
+    fn try_should_be_serde_eq<E: Serialize + ?Sized>(
+        &self,
+        expected: &E,
+    ) -> Result<(), AssertionError>;
+
+    /// Non-panicking version of 'should_be_serde_eq_with'.
+    fn try_should_be_serde_eq_with<E: Serialize + ?Sized>(
+        &self,
+        expected: &E,
+        options: &JsonOptions,
+    ) -> Result<(), AssertionError>;
+}
+
+impl<T: Serialize + ?Sized> ShouldBeSerdeExtension for T {
+    fn should_be_serde_eq<E: Serialize + ?Sized>(&self, expected: &E) {
+        assert_result(self.try_should_be_serde_eq(expected));
+    }
+
+    fn should_be_serde_eq_with<E: Serialize + ?Sized>(&self, expected: &E, options: &JsonOptions) {
+        assert_result(self.try_should_be_serde_eq_with(expected, options));
+    }
+
+    fn try_should_be_serde_eq<E: Serialize + ?Sized>(
+        &self,
+        expected: &E,
+    ) -> Result<(), AssertionError> {
+        self.try_should_be_serde_eq_with(expected, &JsonOptions::new())
+    }
+
+    fn try_should_be_serde_eq_with<E: Serialize + ?Sized>(
+        &self,
+        expected: &E,
+        options: &JsonOptions,
+    ) -> Result<(), AssertionError> {
+        let (actual, expected) = match (to_tree(self), to_tree(expected)) {
+            (Ok(actual), Ok(expected)) => (actual, expected),
+            (Err(error), _) | (_, Err(error)) => {
+                let mut failure = message_generator::failed_condition_message(
+                    (),
+                    AssertionContextBuilder::new()
+                        .verb("should be structurally equal")
+                        .actual_mapper(Box::new(|_| "".to_string()))
+                        .into(),
+                );
+                failure.details = Some(format!("Could not serialize the values: {error}"));
+                return Err(failure);
+            }
+        };
+
+        let mut mismatches = Vec::new();
+        compare(&expected, &actual, "", "", options, &mut mismatches);
+        let passed = mismatches.is_empty();
+
+        check_comparison(
+            &actual,
+            |_| passed,
+            &actual,
+            &expected,
+            AssertionContextBuilder::new().verb("should be structurally equal to"),
+            |actual, expected, context| {
+                let mut error =
+                    message_generator::expected_vs_actual_message(actual, expected, context);
+                error.details = Some(describe_mismatches(&mismatches));
+                error
+            },
+        )
+    }
+}
+
+/// Serialize 'value' into the tree it is compared as.
+fn to_tree<T: Serialize + ?Sized>(value: &T) -> Result<Tree, SerializeError> {
+    value.serialize(TreeSerializer)
+}
+
+/// Builds a 'Tree' with the same shape as 'serde_json' would produce, but
+/// keeping 128-bit integers, non-finite floats and map keys of any type.
+struct TreeSerializer;
+
+/// An error raised by the value's own 'Serialize' implementation.
+#[derive(Debug)]
+struct SerializeError(String);
+
+impl Display for SerializeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for SerializeError {}
+
+impl ser::Error for SerializeError {
+    fn custom<M: Display>(message: M) -> Self {
+        SerializeError(message.to_string())
+    }
+}
+
+macro_rules! serialize_numbers {
+    ($number:path: $($method:ident($type:ty)),* $(,)?) => {
+        $(
+            fn $method(self, value: $type) -> Result<Tree, SerializeError> {
+                Ok(Tree::Number($number(value.into())))
+            }
+        )*
+    };
+}
+
+impl Serializer for TreeSerializer {
+    type Ok = Tree;
+    type Error = SerializeError;
+    type SerializeSeq = ArrayBuilder;
+    type SerializeTuple = ArrayBuilder;
+    type SerializeTupleStruct = ArrayBuilder;
+    type SerializeTupleVariant = ArrayBuilder;
+    type SerializeMap = MapBuilder;
+    type SerializeStruct = MapBuilder;
+    type SerializeStructVariant = MapBuilder;
+
+    serialize_numbers!(Number::signed:
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_i128(i128),
+    );
+    serialize_numbers!(Number::Unsigned:
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_u128(u128),
+    );
+    serialize_numbers!(Number::Float: serialize_f32(f32), serialize_f64(f64));
+
+    fn serialize_bool(self, value: bool) -> Result<Tree, SerializeError> {
+        Ok(Tree::Bool(value))
+    }
+
+    fn serialize_char(self, value: char) -> Result<Tree, SerializeError> {
+        Ok(Tree::String(value.to_string()))
+    }
+
+    fn serialize_str(self, value: &str) -> Result<Tree, SerializeError> {
+        Ok(Tree::String(value.to_string()))
+    }
+
+    fn serialize_bytes(self, value: &[u8]) -> Result<Tree, SerializeError> {
+        let bytes = value
+            .iter()
+            .map(|&byte| Tree::Number(Number::Unsigned(byte.into())));
+        Ok(Tree::Array(bytes.collect()))
+    }
+
+    fn serialize_none(self) -> Result<Tree, SerializeError> {
+        Ok(Tree::Null)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Tree, SerializeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Tree, SerializeError> {
+        Ok(Tree::Null)
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<Tree, SerializeError> {
+        Ok(Tree::Null)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<Tree, SerializeError> {
+        Ok(Tree::String(variant.to_string()))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<Tree, SerializeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Tree, SerializeError> {
+        Ok(tag_variant(Some(variant), value.serialize(self)?))
+    }
+
+    fn serialize_seq(self, length: Option<usize>) -> Result<ArrayBuilder, SerializeError> {
+        Ok(ArrayBuilder::new(None, length))
+    }
+
+    fn serialize_tuple(self, length: usize) -> Result<ArrayBuilder, SerializeError> {
+        Ok(ArrayBuilder::new(None, Some(length)))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        length: usize,
+    ) -> Result<ArrayBuilder, SerializeError> {
+        Ok(ArrayBuilder::new(None, Some(length)))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        length: usize,
+    ) -> Result<ArrayBuilder, SerializeError> {
+        Ok(ArrayBuilder::new(Some(variant), Some(length)))
+    }
+
+    fn serialize_map(self, length: Option<usize>) -> Result<MapBuilder, SerializeError> {
+        Ok(MapBuilder::new(None, length))
+    }
+
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        length: usize,
+    ) -> Result<MapBuilder, SerializeError> {
+        Ok(MapBuilder::new(None, Some(length)))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        length: usize,
+    ) -> Result<MapBuilder, SerializeError> {
+        Ok(MapBuilder::new(Some(variant), Some(length)))
+    }
+}
+
+/// Wrap the content of an enum 'variant' in a single-entry map keyed by the
+/// variant's name, as 'serde_json' does.
+fn tag_variant(variant: Option<&'static str>, content: Tree) -> Tree {
+    match variant {
+        Some(variant) => Tree::Map(vec![(Tree::String(variant.to_string()), content)]),
+        None => content,
+    }
+}
+
+/// Collects the elements of sequences, tuples and tuple variants.
+struct ArrayBuilder {
+    variant: Option<&'static str>,
+    items: Vec<Tree>,
+}
+
+impl ArrayBuilder {
+    fn new(variant: Option<&'static str>, length: Option<usize>) -> Self {
+        let items = Vec::with_capacity(length.unwrap_or(0));
+        ArrayBuilder { variant, items }
+    }
+
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerializeError> {
+        self.items.push(value.serialize(TreeSerializer)?);
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Tree, SerializeError> {
+        Ok(tag_variant(self.variant, Tree::Array(self.items)))
+    }
+}
+
+macro_rules! build_arrays {
+    ($($trait:ident::$method:ident),* $(,)?) => {
+        $(
+            impl ser::$trait for ArrayBuilder {
+                type Ok = Tree;
+                type Error = SerializeError;
+
+                fn $method<T: Serialize + ?Sized>(
+                    &mut self,
+                    value: &T,
+                ) -> Result<(), SerializeError> {
+                    self.push(value)
+                }
+
+                fn end(self) -> Result<Tree, SerializeError> {
+                    self.finish()
+                }
+            }
+        )*
+    };
+}
+
+build_arrays!(
+    SerializeSeq::serialize_element,
+    SerializeTuple::serialize_element,
+    SerializeTupleStruct::serialize_field,
+    SerializeTupleVariant::serialize_field,
+);
+
+/// Collects the entries of maps, and the fields of structs and struct
+/// variants.
+struct MapBuilder {
+    variant: Option<&'static str>,
+    entries: Vec<(Tree, Tree)>,
+    // The key given by 'serialize_key', waiting for its value.
+    key: Option<Tree>,
+}
+
+impl MapBuilder {
+    fn new(variant: Option<&'static str>, length: Option<usize>) -> Self {
+        let entries = Vec::with_capacity(length.unwrap_or(0));
+        MapBuilder {
+            variant,
+            entries,
+            key: None,
+        }
+    }
+
+    fn field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), SerializeError> {
+        let value = value.serialize(TreeSerializer)?;
+        self.entries.push((Tree::String(key.to_string()), value));
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Tree, SerializeError> {
+        Ok(tag_variant(self.variant, Tree::Map(self.entries)))
+    }
+}
+
+impl ser::SerializeMap for MapBuilder {
+    type Ok = Tree;
+    type Error = SerializeError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), SerializeError> {
+        self.key = Some(key.serialize(TreeSerializer)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerializeError> {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| SerializeError("map value without a key".to_string()))?;
+        self.entries.push((key, value.serialize(TreeSerializer)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Tree, SerializeError> {
+        self.finish()
+    }
+}
+
+macro_rules! build_structs {
+    ($($trait:ident),* $(,)?) => {
+        $(
+            impl ser::$trait for MapBuilder {
+                type Ok = Tree;
+                type Error = SerializeError;
+
+                fn serialize_field<T: Serialize + ?Sized>(
+                    &mut self,
+                    key: &'static str,
+                    value: &T,
+                ) -> Result<(), SerializeError> {
+                    self.field(key, value)
+                }
+
+                fn end(self) -> Result<Tree, SerializeError> {
+                    self.finish()
+                }
+            }
+        )*
+    };
+}
+
+build_structs!(SerializeStruct, SerializeStructVariant);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Serialize;
+    use std::collections::{BTreeMap, HashMap};
+
+    #[derive(Serialize)]
+    struct Config {
+        name: &'static str,
+        retries: u32,
+        limits: BTreeMap<&'static str, f64>,
+    }
+
+    #[derive(Serialize)]
+    struct Service {
+        config: Config,
+        tags: Vec<&'static str>,
+    }
+
+    fn service(retries: u32, tags: Vec<&'static str>) -> Service {
+        Service {
+            config: Config {
+                name: "api",
+                retries,
+                limits: BTreeMap::from([("cpu", 0.5)]),
+            },
+            tags,
+        }
+    }
+
+    #[test]
+    fn compares_structures() {
+        service(3, vec!["a"]).should_be_serde_eq(&service(3, vec!["a"]));
+
+        let error = service(5, vec!["a", "b"])
+            .try_should_be_serde_eq(&service(3, vec!["a"]))
+            .unwrap_err();
+        assert_eq!(error.verb, "should be structurally equal to");
+        assert_eq!(
+            error.details.as_deref(),
+            Some(
+                "2 mismatch(es):\n    \
+                 .config.retries: expected 3, was 5\n    \
+                 .tags[1]: expected nothing, was \"b\""
+            )
+        );
+    }
+
+    #[test]
+    fn compares_with_options() {
+        service(5, vec![]).should_be_serde_eq_with(
+            &serde_json::json!({"config": {"name": "api"}}),
+            &JsonOptions::new().subset(),
+        );
+
+        let result = std::panic::catch_unwind(|| {
+            service(5, vec![]).should_be_serde_eq_with(
+                &service(3, vec![]),
+                &JsonOptions::new().ignore("/config/name"),
+            )
+        });
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn reports_root_scalars() {
+        let error = 5u32.try_should_be_serde_eq(&3u32).unwrap_err();
+        assert_eq!(
+            error.details.as_deref(),
+            Some("1 mismatch(es):\n    .: expected 3, was 5")
+        );
+    }
+
+    #[test]
+    fn compares_non_finite_floats_by_value() {
+        let error = f64::NAN.try_should_be_serde_eq(&None::<f64>).unwrap_err();
+        assert_eq!(
+            error.details.as_deref(),
+            Some("1 mismatch(es):\n    .: expected null, was NaN")
+        );
+        assert!(f64::NAN.try_should_be_serde_eq(&f64::NAN).is_err());
+
+        let limits = BTreeMap::from([("cpu", f64::INFINITY)]);
+        limits.should_be_serde_eq(&limits);
+    }
+
+    #[test]
+    fn compares_maps_with_non_string_keys() {
+        let grid = HashMap::from([((0u8, 1u8), "a"), ((2, 3), "b")]);
+        grid.should_be_serde_eq(&BTreeMap::from([((2u8, 3u8), "b"), ((0, 1), "a")]));
+
+        let error = grid
+            .try_should_be_serde_eq(&HashMap::from([((0u8, 1u8), "a"), ((2, 3), "c")]))
+            .unwrap_err();
+        assert_eq!(
+            error.details.as_deref(),
+            Some("1 mismatch(es):\n    [[2,3]]: expected \"c\", was \"b\"")
+        );
+    }
+
+    #[test]
+    fn compares_128_bit_integers() {
+        u128::MAX.should_be_serde_eq(&u128::MAX);
+        i128::MIN.should_be_serde_eq(&i128::MIN);
+        5u128.should_be_serde_eq(&5i8);
+
+        let error = u128::MAX
+            .try_should_be_serde_eq(&(u128::MAX - 1))
+            .unwrap_err();
+        assert_eq!(
+            error.details.as_deref(),
+            Some(
+                "1 mismatch(es):\n    \
+                 .: expected 340282366920938463463374607431768211454, \
+                 was 340282366920938463463374607431768211455"
+            )
+        );
+    }
+}
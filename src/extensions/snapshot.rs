@@ -0,0 +1,163 @@
+use crate::{assertions::assert_result, error::AssertionError, snapshot};
+
+use std::fmt::{Debug, Display};
+
+pub trait ShouldMatchSnapshotExtension: Debug {
+    /// Assert that the pretty 'Debug' representation of this value matches
+    /// the snapshot stored for the calling test, which is rewritten instead
+    /// when `SHOULD_UPDATE_SNAPSHOTS=1` is set.
+    ///
+    /// Snapshots are stored in a `snapshots` directory next to the test file,
+    /// as `<file>__<test>.snap`, where `<test>` is the test's path with `::`
+    /// replaced by `__`. `-2`, `-3`, ... are appended to the name for each
+    /// further snapshot taken by the same test.
+    fn should_match_snapshot(&self) {
+        assert_result(self.try_should_match_snapshot());
+    }
+
+    /// Assert that the pretty 'Debug' representation of this value matches
+    /// the given 'expected' inline snapshot. When `SHOULD_UPDATE_SNAPSHOTS=1`
+    /// is set, a mismatching literal is rewritten in the test's source instead.
+    ///
+    /// Multi-line snapshots may start on the line after the opening quote and
+    /// be indented, which is ignored when comparing.
+    fn should_match_inline_snapshot(&self, expected: &str) {
+        assert_result(self.try_should_match_inline_snapshot(expected));
+    }
+
+    /// Non-panicking version of 'should_match_snapshot'.
+    fn try_should_match_snapshot(&self) -> Result<(), AssertionError> {
+        snapshot::check(&format!("{self:#?}"))
+    }
+
+    /// Non-panicking version of 'should_match_inline_snapshot'.
+    fn try_should_match_inline_snapshot(&self, expected: &str) -> Result<(), AssertionError> {
+        snapshot::check_inline(&format!("{self:#?}"), expected)
+    }
+}
+
+impl<T> ShouldMatchSnapshotExtension for T where T: Debug + ?Sized {}
+
+pub trait ShouldMatchDisplaySnapshotExtension: Display {
+    /// Like 'should_match_snapshot', but storing the 'Display' representation,
+    /// e.g. to snapshot rendered text without quotes and escapes.
+    fn should_match_display_snapshot(&self) {
+        assert_result(self.try_should_match_display_snapshot());
+    }
+
+    /// Like 'should_match_inline_snapshot', but comparing the 'Display'
+    /// representation.
+    fn should_match_inline_display_snapshot(&self, expected: &str) {
+        assert_result(self.try_should_match_inline_display_snapshot(expected));
+    }
+
+    /// Non-panicking version of 'should_match_display_snapshot'.
+    fn try_should_match_display_snapshot(&self) -> Result<(), AssertionError> {
+        snapshot::check(&self.to_string())
+    }
+
+    /// Non-panicking version of 'should_match_inline_display_snapshot'.
+    fn try_should_match_inline_display_snapshot(
+        &self,
+        expected: &str,
+    ) -> Result<(), AssertionError> {
+        snapshot::check_inline(&self.to_string(), expected)
+    }
+}
+
+impl<T> ShouldMatchDisplaySnapshotExtension for T where T: Display + ?Sized {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    struct Report {
+        title: &'static str,
+        rows: Vec<(u32, &'static str)>,
+    }
+
+    #[test]
+    fn matches_stored_snapshots() {
+        Report {
+            title: "Totals",
+            rows: vec![(1, "apples"), (2, "pears")],
+        }
+        .should_match_snapshot();
+
+        "Totals\n  1 apples\n  2 pears".should_match_display_snapshot();
+    }
+
+    #[test]
+    fn matches_inline_snapshots() {
+        (1, "a").should_match_inline_snapshot(
+            r#"
+            (
+                1,
+                "a",
+            )
+            "#,
+        );
+        "plain text".should_match_inline_display_snapshot("plain text");
+
+        // Without isolation, running with SHOULD_UPDATE_SNAPSHOTS=1 would
+        // rewrite the mismatching literal.
+        let error = crate::snapshot::isolated(&std::env::temp_dir(), || {
+            2.try_should_match_inline_display_snapshot("3")
+        })
+        .unwrap_err();
+        assert_eq!(error.verb, "should match its inline snapshot");
+    }
+
+    #[test]
+    fn fails_on_mismatch() {
+        let directory =
+            std::env::temp_dir().join(format!("should-snapshots-mismatch-{}", std::process::id()));
+        let path = directory.join("snapshot__extensions__snapshot__tests__fails_on_mismatch.snap");
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(&path, "stored value\n").unwrap();
+
+        let error = crate::snapshot::isolated(&directory, || {
+            "something else".try_should_match_display_snapshot()
+        })
+        .unwrap_err();
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        assert!(error
+            .verb
+            .ends_with("snapshot__extensions__snapshot__tests__fails_on_mismatch.snap"));
+        assert!(error
+            .details
+            .unwrap()
+            .starts_with("Diff (-expected +actual):\n- stored value\n+ something else"));
+    }
+
+    #[test]
+    fn names_snapshots_after_the_test() {
+        let directory =
+            std::env::temp_dir().join(format!("should-snapshots-helper-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(directory.join("snapshot__a__renders.snap"), "from a\n").unwrap();
+        std::fs::write(directory.join("snapshot__b__renders.snap"), "from b\n").unwrap();
+
+        fn helper(value: &str) {
+            value.should_match_display_snapshot();
+        }
+
+        // Run as the tests `a::renders` and `b::renders`, which both assert
+        // through the same helper.
+        let run = |test: &str, value: &'static str| {
+            let directory = directory.clone();
+            std::thread::Builder::new()
+                .name(test.to_string())
+                .spawn(move || crate::snapshot::isolated(&directory, || helper(value)))
+                .unwrap()
+                .join()
+        };
+        let results = [run("a::renders", "from a"), run("b::renders", "from b")];
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        assert!(results.iter().all(Result::is_ok));
+    }
+}
@@ -1,37 +1,213 @@
 use crate::{
-    assertions::{assert_comparison, assert_unary},
+    assertions::{assert_result, check_comparison, check_unary, unwrap_result},
     context::AssertionContextBuilder,
-    message_generator,
+    diff,
+    error::AssertionError,
+    message_generator, pattern,
 };
 
-use std::fmt::Debug;
+use regex::Regex;
+use std::collections::HashMap;
+use std::fmt::{Debug, Display, Formatter};
+
+/// How strings are normalized before being compared by 'should_equal_normalized'.
+/// Nothing is normalized by default; chain the methods to enable each step.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Normalization {
+    /// Convert "\r\n" and lone "\r" line endings to "\n".
+    pub newlines: bool,
+    /// Remove leading and trailing whitespace.
+    pub trim: bool,
+    /// Replace each run of whitespace, including newlines, with a single space.
+    pub collapse_whitespace: bool,
+    /// Compare case-insensitively, by upper-casing and then lower-casing.
+    /// This approximates Unicode case folding but does not equal it for every
+    /// character, e.g. 'ẞ' is not equated with "ss".
+    pub ignore_case: bool,
+}
+
+impl Normalization {
+    /// A normalization which leaves strings unchanged.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Also convert "\r\n" and lone "\r" line endings to "\n".
+    pub fn newlines(mut self) -> Self {
+        self.newlines = true;
+        self
+    }
+
+    /// Also remove leading and trailing whitespace.
+    pub fn trim(mut self) -> Self {
+        self.trim = true;
+        self
+    }
+
+    /// Also replace each run of whitespace with a single space.
+    pub fn collapse_whitespace(mut self) -> Self {
+        self.collapse_whitespace = true;
+        self
+    }
+
+    /// Also compare case-insensitively, see the 'ignore_case' field.
+    pub fn ignore_case(mut self) -> Self {
+        self.ignore_case = true;
+        self
+    }
+
+    /// Normalize the given 'text' as configured.
+    pub fn apply(&self, text: &str) -> String {
+        let mut text = text.to_string();
+
+        if self.newlines {
+            text = text.replace("\r\n", "\n").replace('\r', "\n");
+        }
+        if self.trim {
+            text = text.trim().to_string();
+        }
+        if self.collapse_whitespace {
+            text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        }
+        if self.ignore_case {
+            // Upper-casing first folds characters such as 'ß' and 'ς' which
+            // have no single lower-case equivalent of their capital.
+            text = text.to_uppercase().to_lowercase();
+        }
+
+        text
+    }
+}
+
+impl Display for Normalization {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let ignored: Vec<&str> = [
+            (self.ignore_case, "case"),
+            (self.collapse_whitespace, "whitespace differences"),
+            (self.newlines, "line endings"),
+            (self.trim, "surrounding whitespace"),
+        ]
+        .into_iter()
+        .filter_map(|(enabled, name)| enabled.then_some(name))
+        .collect();
+
+        match ignored.split_last() {
+            None => write!(f, "exactly"),
+            Some((last, [])) => write!(f, "ignoring {last}"),
+            Some((last, rest)) => write!(f, "ignoring {} and {last}", rest.join(", ")),
+        }
+    }
+}
 
 pub trait ShouldBeStringExtension: AsRef<str> + Debug {
     /// Assert that this string should be empty.
     fn should_be_empty(&self) {
-        assert_unary(
+        assert_result(self.try_should_be_empty());
+    }
+
+    /// Assert that this string should not be empty.
+    fn should_not_be_empty(&self) {
+        assert_result(self.try_should_not_be_empty());
+    }
+
+    /// Assert that this string should be the given 'length'.
+    fn should_be_len(&self, length: usize) {
+        assert_result(self.try_should_be_len(length));
+    }
+
+    /// Assert that this string contains the given 'pattern'.
+    // Eventually would like to swap arg to [`std::str::pattern::Pattern`], see
+    // https://github.com/rust-lang/rust/issues/27721
+    fn should_contain(&self, pattern: &str) {
+        assert_result(self.try_should_contain(pattern));
+    }
+
+    /// Assert that this string does not contain the given 'pattern'.
+    // Eventually would like to swap arg to [`std::str::pattern::Pattern`], see
+    // https://github.com/rust-lang/rust/issues/27721
+    fn should_not_contain(&self, pattern: &str) {
+        assert_result(self.try_should_not_contain(pattern));
+    }
+
+    /// Assert that this string starts with the given 'prefix'.
+    fn should_start_with(&self, prefix: &str) {
+        assert_result(self.try_should_start_with(prefix));
+    }
+
+    /// Assert that this string ends with the given 'suffix'.
+    fn should_end_with(&self, suffix: &str) {
+        assert_result(self.try_should_end_with(suffix));
+    }
+
+    /// Assert that this string equals the given 'expected', describing any
+    /// difference with a line diff of the two texts.
+    fn should_equal_text(&self, expected: &str) {
+        assert_result(self.try_should_equal_text(expected));
+    }
+
+    /// Assert that this string equals the given 'expected', ignoring case.
+    fn should_equal_ignoring_case(&self, expected: &str) {
+        assert_result(self.try_should_equal_ignoring_case(expected));
+    }
+
+    /// Assert that this string contains the given 'pattern', ignoring case.
+    fn should_contain_ignoring_case(&self, pattern: &str) {
+        assert_result(self.try_should_contain_ignoring_case(pattern));
+    }
+
+    /// Assert that this string equals the given 'expected' once both have
+    /// been normalized as described by 'normalization'.
+    fn should_equal_normalized(&self, expected: &str, normalization: Normalization) {
+        assert_result(self.try_should_equal_normalized(expected, normalization));
+    }
+
+    /// Assert that the given regex 'pattern' matches somewhere in this string.
+    fn should_match(&self, pattern: &str) {
+        assert_result(self.try_should_match(pattern));
+    }
+
+    /// Assert that the given regex 'pattern' matches nowhere in this string.
+    fn should_not_match(&self, pattern: &str) {
+        assert_result(self.try_should_not_match(pattern));
+    }
+
+    /// Assert that the given shell-style 'glob' matches this whole string.
+    /// Supports `*`, `?`, `[...]` and `[!...]`, with `\` escaping.
+    fn should_match_glob(&self, glob: &str) {
+        assert_result(self.try_should_match_glob(glob));
+    }
+
+    /// Assert that the given regex 'pattern' matches somewhere in this string,
+    /// and return the text of each named group in the first match.
+    fn should_match_with_captures(&self, pattern: &str) -> HashMap<String, String> {
+        unwrap_result(self.try_should_match_with_captures(pattern))
+    }
+
+    /// Non-panicking version of 'should_be_empty'.
+    fn try_should_be_empty(&self) -> Result<(), AssertionError> {
+        check_unary(
             self.as_ref(),
             |x| x.is_empty(),
             self.as_ref(),
             AssertionContextBuilder::new().verb("should be empty"),
             message_generator::failed_condition_message,
-        );
+        )
     }
 
-    /// Assert that this string should not be empty.
-    fn should_not_be_empty(&self) {
-        assert_unary(
+    /// Non-panicking version of 'should_not_be_empty'.
+    fn try_should_not_be_empty(&self) -> Result<(), AssertionError> {
+        check_unary(
             self.as_ref(),
             |x| !x.is_empty(),
             self.as_ref(),
-            AssertionContextBuilder::new().verb("should be empty"),
+            AssertionContextBuilder::new().verb("should not be empty"),
             message_generator::failed_condition_message,
-        );
+        )
     }
 
-    /// Assert that this string should be the given 'length'.
-    fn should_be_len(&self, length: usize) {
-        assert_comparison(
+    /// Non-panicking version of 'should_be_len'.
+    fn try_should_be_len(&self, length: usize) -> Result<(), AssertionError> {
+        check_comparison(
             self.as_ref(),
             |x| x.len() == length,
             self.as_ref(),
@@ -42,43 +218,207 @@ pub trait ShouldBeStringExtension: AsRef<str> + Debug {
                     format!(" length {}, actual={x}", x.len())
                 })),
             message_generator::expected_vs_actual_message,
-        );
+        )
     }
 
-    /// Assert that this string contains the given 'pattern'.
-    // Eventually would like to swap arg to [`std::str::pattern::Pattern`], see
-    // https://github.com/rust-lang/rust/issues/27721
-    fn should_contain(&self, pattern: &str) {
-        assert_comparison(
+    /// Non-panicking version of 'should_contain'.
+    fn try_should_contain(&self, pattern: &str) -> Result<(), AssertionError> {
+        check_comparison(
             self.as_ref(),
             |x| x.contains(pattern),
             self.as_ref(),
             pattern,
             AssertionContextBuilder::new().verb("should contain"),
             message_generator::expected_vs_actual_message,
-        );
+        )
     }
 
-    /// Assert that this string does not contain the given 'pattern'.
-    // Eventually would like to swap arg to [`std::str::pattern::Pattern`], see
-    // https://github.com/rust-lang/rust/issues/27721
-    fn should_not_contain(&self, pattern: &str) {
-        assert_comparison(
+    /// Non-panicking version of 'should_not_contain'.
+    fn try_should_not_contain(&self, pattern: &str) -> Result<(), AssertionError> {
+        check_comparison(
             self.as_ref(),
             |x| !x.contains(pattern),
             self.as_ref(),
             pattern,
             AssertionContextBuilder::new().verb("should not contain"),
             message_generator::expected_vs_actual_message,
-        );
+        )
+    }
+
+    /// Non-panicking version of 'should_start_with'.
+    fn try_should_start_with(&self, prefix: &str) -> Result<(), AssertionError> {
+        check_comparison(
+            self.as_ref(),
+            |x| x.starts_with(prefix),
+            self.as_ref(),
+            prefix,
+            AssertionContextBuilder::new().verb("should start with"),
+            message_generator::expected_vs_actual_message,
+        )
+    }
+
+    /// Non-panicking version of 'should_end_with'.
+    fn try_should_end_with(&self, suffix: &str) -> Result<(), AssertionError> {
+        check_comparison(
+            self.as_ref(),
+            |x| x.ends_with(suffix),
+            self.as_ref(),
+            suffix,
+            AssertionContextBuilder::new().verb("should end with"),
+            message_generator::expected_vs_actual_message,
+        )
+    }
+
+    /// Non-panicking version of 'should_equal_text'.
+    fn try_should_equal_text(&self, expected: &str) -> Result<(), AssertionError> {
+        check_comparison(
+            self.as_ref(),
+            |x| x == expected,
+            self.as_ref(),
+            expected,
+            AssertionContextBuilder::new().verb("should equal"),
+            |actual, expected, context| {
+                let mut error =
+                    message_generator::expected_vs_actual_message(actual, expected, context);
+                error.details = Some(diff::text_diff(expected, actual));
+                error
+            },
+        )
+    }
+
+    /// Non-panicking version of 'should_equal_ignoring_case'.
+    fn try_should_equal_ignoring_case(&self, expected: &str) -> Result<(), AssertionError> {
+        self.try_should_equal_normalized(expected, Normalization::new().ignore_case())
+    }
+
+    /// Non-panicking version of 'should_contain_ignoring_case'.
+    fn try_should_contain_ignoring_case(&self, pattern: &str) -> Result<(), AssertionError> {
+        let normalization = Normalization::new().ignore_case();
+        let contained = normalization
+            .apply(self.as_ref())
+            .contains(&normalization.apply(pattern));
+
+        check_comparison(
+            self.as_ref(),
+            |_| contained,
+            self.as_ref(),
+            pattern,
+            AssertionContextBuilder::new().verb(&format!("should contain ({normalization})")),
+            message_generator::expected_vs_actual_message,
+        )
+    }
+
+    /// Non-panicking version of 'should_equal_normalized'.
+    fn try_should_equal_normalized(
+        &self,
+        expected: &str,
+        normalization: Normalization,
+    ) -> Result<(), AssertionError> {
+        let normalized_actual = normalization.apply(self.as_ref());
+        let normalized_expected = normalization.apply(expected);
+        let passed = normalized_actual == normalized_expected;
+
+        check_comparison(
+            self.as_ref(),
+            |_| passed,
+            self.as_ref(),
+            expected,
+            AssertionContextBuilder::new().verb(&format!("should equal ({normalization})")),
+            |actual, expected, context| {
+                let mut error =
+                    message_generator::expected_vs_actual_message(actual, expected, context);
+
+                let mut details = format!(
+                    "After normalization:\n    expected {normalized_expected:?}\n    but was  {normalized_actual:?}"
+                );
+                if normalized_expected.contains('\n') || normalized_actual.contains('\n') {
+                    details.push_str("\n\n");
+                    details.push_str(&diff::text_diff(&normalized_expected, &normalized_actual));
+                }
+                error.details = Some(details);
+                error
+            },
+        )
+    }
+
+    /// Non-panicking version of 'should_match'.
+    fn try_should_match(&self, pattern: &str) -> Result<(), AssertionError> {
+        check_pattern(self.as_ref(), pattern, pattern, "should match", true)
+    }
+
+    /// Non-panicking version of 'should_not_match'.
+    fn try_should_not_match(&self, pattern: &str) -> Result<(), AssertionError> {
+        check_pattern(self.as_ref(), pattern, pattern, "should not match", false)
+    }
+
+    /// Non-panicking version of 'should_match_glob'.
+    fn try_should_match_glob(&self, glob: &str) -> Result<(), AssertionError> {
+        let regex = pattern::glob_to_regex(glob);
+        check_pattern(self.as_ref(), glob, &regex, "should match the glob", true)
+    }
+
+    /// Non-panicking version of 'should_match_with_captures'.
+    fn try_should_match_with_captures(
+        &self,
+        pattern: &str,
+    ) -> Result<HashMap<String, String>, AssertionError> {
+        check_pattern(self.as_ref(), pattern, pattern, "should match", true)?;
+
+        // The pattern is known to be valid and to match by now.
+        let regex = Regex::new(pattern).expect("pattern was already compiled");
+        let captures = regex
+            .captures(self.as_ref())
+            .expect("pattern already matched");
+        Ok(regex
+            .capture_names()
+            .flatten()
+            .filter_map(|name| {
+                let group = captures.name(name)?;
+                Some((name.to_string(), group.as_str().to_string()))
+            })
+            .collect())
     }
 }
 
 impl<T> ShouldBeStringExtension for T where T: AsRef<str> + Debug {}
 
+/// Check whether the given 'regex' matches somewhere in 'actual' as expected
+/// by 'should_match', reporting the 'pattern' it was written as.
+fn check_pattern(
+    actual: &str,
+    pattern: &str,
+    regex: &str,
+    verb: &str,
+    should_match: bool,
+) -> Result<(), AssertionError> {
+    let (passed, details) = match Regex::new(regex) {
+        Err(error) => (false, Some(format!("Invalid pattern: {error}"))),
+        Ok(compiled) => match (compiled.find(actual), should_match) {
+            (Some(_), true) | (None, false) => (true, None),
+            (None, true) => (false, Some(pattern::mismatch_details(regex, actual))),
+            (Some(found), false) => (false, Some(pattern::match_details(actual, found.range()))),
+        },
+    };
+
+    check_comparison(
+        actual,
+        |_| passed,
+        actual,
+        pattern,
+        AssertionContextBuilder::new().verb(verb),
+        |actual, expected, context| {
+            let mut error =
+                message_generator::expected_vs_actual_message(actual, expected, context);
+            error.details = details;
+            error
+        },
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ShouldEqualExtension;
 
     #[test]
     fn test_str_should_be_empty() {
@@ -94,6 +434,9 @@ mod tests {
 
         let result = std::panic::catch_unwind(|| "".should_not_be_empty());
         assert!(result.is_err());
+
+        let error = "".try_should_not_be_empty().unwrap_err();
+        assert_eq!(error.verb, "should not be empty");
     }
 
     #[test]
@@ -162,4 +505,153 @@ mod tests {
         let result = std::panic::catch_unwind(|| hello.should_not_contain(&hello[0..2]));
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_try_should_contain() {
+        "hello world".try_should_contain("world").unwrap();
+
+        let error = "hello".try_should_contain("world").unwrap_err();
+        assert_eq!(error.verb, "should contain");
+        assert_eq!(error.expected.as_deref(), Some("\"world\""));
+        assert_eq!(error.actual, "\"hello\"");
+    }
+
+    #[test]
+    fn test_try_should_be_len() {
+        let error = "hello".try_should_be_len(4).unwrap_err();
+        assert_eq!(error.expected.as_deref(), Some("4"));
+        assert_eq!(error.actual, "length 5, actual=hello");
+    }
+
+    #[test]
+    fn test_should_match() {
+        "order 123 shipped".should_match(r"order \d+");
+
+        let error = "see order 12a shipped"
+            .try_should_match(r"order \d+ shipped")
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            " should match \"order \\\\d+ shipped\" but was \"see order 12a shipped\"\n\n\
+             Closest partial match:\n    \
+             \"see order 12a shipped\"\n    \
+             \x20    ^^^^^^^^"
+        );
+
+        let error = "abc".try_should_match("(").unwrap_err();
+        assert!(error.details.unwrap().starts_with("Invalid pattern:"));
+    }
+
+    #[test]
+    fn test_should_not_match() {
+        "hello".should_not_match(r"\d");
+
+        let error = "room 42".try_should_not_match(r"\d+").unwrap_err();
+        assert_eq!(
+            error.details.as_deref(),
+            Some("Matched here:\n    \"room 42\"\n          ^^")
+        );
+    }
+
+    #[test]
+    fn test_should_match_glob() {
+        "src/lib.rs".should_match_glob("src/*.rs");
+
+        let result = std::panic::catch_unwind(|| "src/lib.rs.bak".should_match_glob("*.rs"));
+        assert!(result.is_err());
+
+        let error = "a.txt".try_should_match_glob("*.rs").unwrap_err();
+        assert_eq!(error.verb, "should match the glob");
+        assert_eq!(error.expected.as_deref(), Some("\"*.rs\""));
+    }
+
+    #[test]
+    fn test_should_match_with_captures() {
+        let captures = "v1.20-beta".should_match_with_captures(
+            r"v(?<major>\d+)\.(?<minor>\d+)(-(?<pre>\w+))?(?<build>\+\d+)?",
+        );
+        captures["major"].should_equal("1");
+        captures["minor"].should_equal("20");
+        captures["pre"].should_equal("beta");
+        assert!(!captures.contains_key("build"));
+
+        let result =
+            std::panic::catch_unwind(|| "v1".should_match_with_captures(r"v(?<major>\d+)\."));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_should_start_and_end_with() {
+        "hello world".should_start_with("hello");
+        "hello world".should_end_with("world");
+
+        let error = "hello".try_should_start_with("world").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            " should start with \"world\" but was \"hello\""
+        );
+
+        let result = std::panic::catch_unwind(|| "hello".should_end_with("hell"));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_should_equal_text() {
+        "one\ntwo".to_string().should_equal_text("one\ntwo");
+
+        let error = "one\r\ntwo \nthree"
+            .try_should_equal_text("one\ntwo\nthree")
+            .unwrap_err();
+        assert_eq!(
+            error.details.as_deref(),
+            Some(
+                "Diff (-expected +actual):\n\
+                 - one\n\
+                 - two\n\
+                 + one␍\n\
+                 ?    ^\n\
+                 + two·\n\
+                 ?    ^\n  \
+                 three"
+            )
+        );
+    }
+
+    #[test]
+    fn test_should_equal_ignoring_case() {
+        "Straße".should_equal_ignoring_case("STRASSE");
+        "ΟΔΟΣ".should_equal_ignoring_case("οδος");
+
+        let error = "Hello".try_should_equal_ignoring_case("world").unwrap_err();
+        assert_eq!(error.verb, "should equal (ignoring case)");
+        assert_eq!(
+            error.details.as_deref(),
+            Some("After normalization:\n    expected \"world\"\n    but was  \"hello\"")
+        );
+    }
+
+    #[test]
+    fn test_should_contain_ignoring_case() {
+        "Error: FILE NOT FOUND".should_contain_ignoring_case("file not found");
+
+        let result = std::panic::catch_unwind(|| "Hello".should_contain_ignoring_case("world"));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_should_equal_normalized() {
+        "line one\r\nline two\r\n"
+            .should_equal_normalized("line one\nline two\n", Normalization::new().newlines());
+        "  a \t b\n c  "
+            .should_equal_normalized("a b c", Normalization::new().trim().collapse_whitespace());
+
+        let error = "one\r\ntwo\r\n"
+            .try_should_equal_normalized("one\nthree\n", Normalization::new().newlines().trim())
+            .unwrap_err();
+        assert_eq!(
+            error.verb,
+            "should equal (ignoring line endings and surrounding whitespace)"
+        );
+        assert!(error.details.unwrap().contains("- three\n+ two"));
+    }
 }
@@ -1,13 +1,44 @@
 mod assertions;
 mod code_grabber;
+mod config;
 mod context;
+mod diff;
+mod error;
 mod extensions;
+pub mod matchers;
 mod message_generator;
 mod panic;
+mod pattern;
+mod snapshot;
+mod soft;
+#[cfg(feature = "json")]
+mod tree;
 
 // Export the public extensions.
 pub use extensions::base::ShouldSatisfyExtension;
 pub use extensions::eq::ShouldBeEqExtension;
+pub use extensions::error::ShouldBeErrorExtension;
+pub use extensions::float::{ShouldBeApproxExtension, ShouldBeApproxIterExtension};
+pub use extensions::hash_iter::ShouldBeHashIterExtension;
 pub use extensions::iter::ShouldBeIntoIterExtension;
+#[cfg(feature = "json")]
+pub use extensions::json::{JsonOptions, ShouldBeJsonExtension};
+pub use extensions::matcher::ShouldMatchExtension;
+pub use extensions::option::ShouldBeOptionExtension;
+pub use extensions::ordered_iter::ShouldBeOrderedIterExtension;
+pub use extensions::panic::ShouldPanicExtension;
+pub use extensions::partial_eq::ShouldEqualExtension;
 pub use extensions::partial_ord::ShouldBePartialOrdExtension;
+pub use extensions::result::ShouldBeResultExtension;
+#[cfg(feature = "serde")]
+pub use extensions::serde::ShouldBeSerdeExtension;
+pub use extensions::snapshot::{ShouldMatchDisplaySnapshotExtension, ShouldMatchSnapshotExtension};
 pub use extensions::string::ShouldBeStringExtension;
+
+pub use config::set_max_reported_elements;
+pub use error::{AssertionError, AssertionFailure, SoftAssertionFailures};
+pub use extensions::float::{ApproxFloat, Tolerance};
+pub use extensions::partial_ord::CheckedSub;
+pub use extensions::string::Normalization;
+pub use matchers::{MatchResult, Matcher};
+pub use soft::soft;
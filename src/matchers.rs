@@ -0,0 +1,494 @@
+//! Composable matchers for use with 'should', e.g.
+//! `value.should(all_of([gt(1).boxed(), lt(5).boxed()]))`.
+
+use crate::config;
+
+use std::collections::{BTreeSet, HashSet, VecDeque};
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+/// Whether a value satisfied a matcher.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchResult {
+    Match,
+    Mismatch,
+}
+
+impl MatchResult {
+    pub fn is_match(self) -> bool {
+        self == MatchResult::Match
+    }
+}
+
+impl From<bool> for MatchResult {
+    fn from(value: bool) -> Self {
+        if value {
+            MatchResult::Match
+        } else {
+            MatchResult::Mismatch
+        }
+    }
+}
+
+/// A condition on values of type 'T', which describes itself for failure
+/// messages. Implement this to build domain-specific matchers.
+pub trait Matcher<T: Debug + ?Sized> {
+    /// Check whether 'actual' satisfies this matcher.
+    fn matches(&self, actual: &T) -> MatchResult;
+
+    /// Describe what this matcher expects, e.g. "less than 5".
+    fn describe(&self) -> String;
+
+    /// Explain why 'actual' did not satisfy this matcher, e.g. which
+    /// element failed, or 'None' if the value itself says enough.
+    fn describe_mismatch(&self, _actual: &T) -> Option<String> {
+        None
+    }
+
+    /// Box this matcher, e.g. to combine it with others in 'all_of'.
+    fn boxed<'a>(self) -> Box<dyn Matcher<T> + 'a>
+    where
+        Self: Sized + 'a,
+    {
+        Box::new(self)
+    }
+}
+
+impl<T: Debug + ?Sized> Matcher<T> for Box<dyn Matcher<T> + '_> {
+    fn matches(&self, actual: &T) -> MatchResult {
+        self.as_ref().matches(actual)
+    }
+
+    fn describe(&self) -> String {
+        self.as_ref().describe()
+    }
+
+    fn describe_mismatch(&self, actual: &T) -> Option<String> {
+        self.as_ref().describe_mismatch(actual)
+    }
+}
+
+/// See 'eq'.
+pub struct EqualTo<E>(E);
+
+/// Match values equal to 'expected'.
+pub fn eq<E>(expected: E) -> EqualTo<E> {
+    EqualTo(expected)
+}
+
+impl<T: PartialEq<E> + Debug + ?Sized, E: Debug> Matcher<T> for EqualTo<E> {
+    fn matches(&self, actual: &T) -> MatchResult {
+        (*actual == self.0).into()
+    }
+
+    fn describe(&self) -> String {
+        format!("equal to {:?}", self.0)
+    }
+}
+
+/// See 'lt', 'le', 'gt' and 'ge'.
+pub struct Compare<E> {
+    bound: E,
+    accepts: fn(std::cmp::Ordering) -> bool,
+    relation: &'static str,
+}
+
+/// Match values less than 'bound'.
+pub fn lt<E>(bound: E) -> Compare<E> {
+    Compare {
+        bound,
+        accepts: std::cmp::Ordering::is_lt,
+        relation: "less than",
+    }
+}
+
+/// Match values less than or equal to 'bound'.
+pub fn le<E>(bound: E) -> Compare<E> {
+    Compare {
+        bound,
+        accepts: std::cmp::Ordering::is_le,
+        relation: "less than or equal to",
+    }
+}
+
+/// Match values greater than 'bound'.
+pub fn gt<E>(bound: E) -> Compare<E> {
+    Compare {
+        bound,
+        accepts: std::cmp::Ordering::is_gt,
+        relation: "greater than",
+    }
+}
+
+/// Match values greater than or equal to 'bound'.
+pub fn ge<E>(bound: E) -> Compare<E> {
+    Compare {
+        bound,
+        accepts: std::cmp::Ordering::is_ge,
+        relation: "greater than or equal to",
+    }
+}
+
+impl<T: PartialOrd<E> + Debug + ?Sized, E: Debug> Matcher<T> for Compare<E> {
+    fn matches(&self, actual: &T) -> MatchResult {
+        actual
+            .partial_cmp(&self.bound)
+            .is_some_and(self.accepts)
+            .into()
+    }
+
+    fn describe(&self) -> String {
+        format!("{} {:?}", self.relation, self.bound)
+    }
+}
+
+/// See 'not'.
+pub struct Not<M>(M);
+
+/// Match values which do not satisfy 'matcher'.
+pub fn not<M>(matcher: M) -> Not<M> {
+    Not(matcher)
+}
+
+impl<T: Debug + ?Sized, M: Matcher<T>> Matcher<T> for Not<M> {
+    fn matches(&self, actual: &T) -> MatchResult {
+        (!self.0.matches(actual).is_match()).into()
+    }
+
+    fn describe(&self) -> String {
+        format!("not {}", self.0.describe())
+    }
+}
+
+/// See 'all_of'.
+pub struct AllOf<'a, T: Debug + ?Sized>(Vec<Box<dyn Matcher<T> + 'a>>);
+
+/// Match values which satisfy every one of the given 'matchers'.
+pub fn all_of<'a, T: Debug + ?Sized>(
+    matchers: impl IntoIterator<Item = Box<dyn Matcher<T> + 'a>>,
+) -> AllOf<'a, T> {
+    AllOf(matchers.into_iter().collect())
+}
+
+impl<T: Debug + ?Sized> Matcher<T> for AllOf<'_, T> {
+    fn matches(&self, actual: &T) -> MatchResult {
+        self.0
+            .iter()
+            .all(|matcher| matcher.matches(actual).is_match())
+            .into()
+    }
+
+    fn describe(&self) -> String {
+        format!("all of ({})", describe_each(&self.0))
+    }
+
+    fn describe_mismatch(&self, actual: &T) -> Option<String> {
+        let failed: Vec<String> = self
+            .0
+            .iter()
+            .filter(|matcher| !matcher.matches(actual).is_match())
+            .map(|matcher| format!("not {}: {}", matcher.describe(), mismatch(matcher, actual)))
+            .collect();
+        Some(failed.join("\n"))
+    }
+}
+
+/// See 'any_of'.
+pub struct AnyOf<'a, T: Debug + ?Sized>(Vec<Box<dyn Matcher<T> + 'a>>);
+
+/// Match values which satisfy at least one of the given 'matchers'.
+pub fn any_of<'a, T: Debug + ?Sized>(
+    matchers: impl IntoIterator<Item = Box<dyn Matcher<T> + 'a>>,
+) -> AnyOf<'a, T> {
+    AnyOf(matchers.into_iter().collect())
+}
+
+impl<T: Debug + ?Sized> Matcher<T> for AnyOf<'_, T> {
+    fn matches(&self, actual: &T) -> MatchResult {
+        self.0
+            .iter()
+            .any(|matcher| matcher.matches(actual).is_match())
+            .into()
+    }
+
+    fn describe(&self) -> String {
+        format!("any of ({})", describe_each(&self.0))
+    }
+}
+
+/// The mismatch description of 'matcher' for 'actual', falling back to the
+/// value itself.
+fn mismatch<T: Debug + ?Sized>(matcher: &impl Matcher<T>, actual: &T) -> String {
+    matcher
+        .describe_mismatch(actual)
+        .unwrap_or_else(|| format!("was {actual:?}"))
+}
+
+fn describe_each<T: Debug + ?Sized>(matchers: &[Box<dyn Matcher<T> + '_>]) -> String {
+    matchers
+        .iter()
+        .map(|matcher| matcher.describe())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Values which can be checked for containing an item by 'contains'.
+pub trait Container<E: ?Sized> {
+    fn contains_item(&self, item: &E) -> bool;
+}
+
+impl<E: PartialEq> Container<E> for [E] {
+    fn contains_item(&self, item: &E) -> bool {
+        self.contains(item)
+    }
+}
+
+impl<E: PartialEq, const N: usize> Container<E> for [E; N] {
+    fn contains_item(&self, item: &E) -> bool {
+        self.contains(item)
+    }
+}
+
+impl<E: PartialEq> Container<E> for Vec<E> {
+    fn contains_item(&self, item: &E) -> bool {
+        self.contains(item)
+    }
+}
+
+impl<E: PartialEq> Container<E> for VecDeque<E> {
+    fn contains_item(&self, item: &E) -> bool {
+        self.contains(item)
+    }
+}
+
+impl<E: Eq + Hash> Container<E> for HashSet<E> {
+    fn contains_item(&self, item: &E) -> bool {
+        self.contains(item)
+    }
+}
+
+impl<E: Ord> Container<E> for BTreeSet<E> {
+    fn contains_item(&self, item: &E) -> bool {
+        self.contains(item)
+    }
+}
+
+impl Container<&str> for str {
+    fn contains_item(&self, item: &&str) -> bool {
+        self.contains(item)
+    }
+}
+
+impl Container<&str> for String {
+    fn contains_item(&self, item: &&str) -> bool {
+        self.contains(item)
+    }
+}
+
+impl Container<char> for str {
+    fn contains_item(&self, item: &char) -> bool {
+        self.contains(*item)
+    }
+}
+
+impl Container<char> for String {
+    fn contains_item(&self, item: &char) -> bool {
+        self.contains(*item)
+    }
+}
+
+/// See 'contains'.
+pub struct Contains<E>(E);
+
+/// Match collections containing 'item', or strings containing it as a
+/// substring or character.
+pub fn contains<E>(item: E) -> Contains<E> {
+    Contains(item)
+}
+
+impl<T: Container<E> + Debug + ?Sized, E: Debug> Matcher<T> for Contains<E> {
+    fn matches(&self, actual: &T) -> MatchResult {
+        actual.contains_item(&self.0).into()
+    }
+
+    fn describe(&self) -> String {
+        format!("containing {:?}", self.0)
+    }
+}
+
+/// See 'each'.
+pub struct Each<M, I: ?Sized> {
+    matcher: M,
+    item: PhantomData<fn(&I)>,
+}
+
+/// Match collections whose every element satisfies 'matcher'.
+pub fn each<M, I: ?Sized>(matcher: M) -> Each<M, I> {
+    Each {
+        matcher,
+        item: PhantomData,
+    }
+}
+
+impl<T, I, M> Matcher<T> for Each<M, I>
+where
+    T: Debug + ?Sized,
+    for<'a> &'a T: IntoIterator<Item = &'a I>,
+    I: Debug,
+    M: Matcher<I>,
+{
+    fn matches(&self, actual: &T) -> MatchResult {
+        actual
+            .into_iter()
+            .all(|item| self.matcher.matches(item).is_match())
+            .into()
+    }
+
+    fn describe(&self) -> String {
+        format!("every element {}", self.matcher.describe())
+    }
+
+    fn describe_mismatch(&self, actual: &T) -> Option<String> {
+        let mismatches: Vec<String> = actual
+            .into_iter()
+            .enumerate()
+            .filter(|(_, item)| !self.matcher.matches(item).is_match())
+            .map(|(index, item)| format!("[{index}] {}", mismatch(&self.matcher, item)))
+            .collect();
+
+        let limit = config::max_reported_elements();
+        let mut description = mismatches[..mismatches.len().min(limit)].join("\n");
+        if mismatches.len() > limit {
+            description.push_str(&format!("\n... and {} more", mismatches.len() - limit));
+        }
+        Some(description)
+    }
+}
+
+/// See 'has_field'.
+pub struct HasField<F, M, T: ?Sized, V: ?Sized> {
+    name: &'static str,
+    field: F,
+    matcher: M,
+    types: PhantomData<fn(&T) -> &V>,
+}
+
+/// Match values whose field, read by the 'field' accessor, satisfies
+/// 'matcher'. The field is reported by the given 'name'.
+pub fn has_field<F, M, T, V>(name: &'static str, field: F, matcher: M) -> HasField<F, M, T, V>
+where
+    T: ?Sized,
+    V: ?Sized,
+    F: Fn(&T) -> &V,
+{
+    HasField {
+        name,
+        field,
+        matcher,
+        types: PhantomData,
+    }
+}
+
+impl<F, M, T, V> Matcher<T> for HasField<F, M, T, V>
+where
+    T: Debug + ?Sized,
+    V: Debug + ?Sized,
+    F: Fn(&T) -> &V,
+    M: Matcher<V>,
+{
+    fn matches(&self, actual: &T) -> MatchResult {
+        self.matcher.matches((self.field)(actual))
+    }
+
+    fn describe(&self) -> String {
+        format!("has field '{}' {}", self.name, self.matcher.describe())
+    }
+
+    fn describe_mismatch(&self, actual: &T) -> Option<String> {
+        let field = (self.field)(actual);
+        Some(format!(
+            "field '{}' {}",
+            self.name,
+            mismatch(&self.matcher, field)
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct Config {
+        retries: u32,
+        hosts: Vec<&'static str>,
+    }
+
+    fn config() -> Config {
+        Config {
+            retries: 3,
+            hosts: vec!["a", "b"],
+        }
+    }
+
+    #[test]
+    fn basic_matchers() {
+        assert!(eq(3).matches(&3).is_match());
+        assert!(lt(3).matches(&2).is_match());
+        assert!(!le(3).matches(&4).is_match());
+        assert!(gt(2.0).matches(&2.5).is_match());
+        assert!(!ge(1.0).matches(&f64::NAN).is_match());
+        assert!(not(eq("a")).matches(&"b").is_match());
+
+        assert_eq!(Matcher::<i32>::describe(&not(lt(3))), "not less than 3");
+        assert_eq!(Matcher::<i32>::describe_mismatch(&eq(3), &4), None);
+    }
+
+    #[test]
+    fn contains_matcher() {
+        assert!(contains(2).matches(&vec![1, 2]).is_match());
+        assert!(contains(2).matches(&[1, 2][..]).is_match());
+        assert!(contains("ell").matches("hello").is_match());
+        assert!(contains('z').matches(&"hello".to_string()) == MatchResult::Mismatch);
+        assert_eq!(Matcher::<str>::describe(&contains("x")), "containing \"x\"");
+    }
+
+    #[test]
+    fn combinators() {
+        let between = all_of([gt(1).boxed(), lt(5).boxed()]);
+        assert!(between.matches(&3).is_match());
+        assert!(!between.matches(&7).is_match());
+        assert_eq!(between.describe(), "all of (greater than 1, less than 5)");
+        assert_eq!(
+            between.describe_mismatch(&7).as_deref(),
+            Some("not less than 5: was 7")
+        );
+
+        let either = any_of([eq(1).boxed(), eq(2).boxed()]);
+        assert!(either.matches(&2).is_match());
+        assert!(!either.matches(&3).is_match());
+    }
+
+    #[test]
+    fn each_and_has_field() {
+        let small = each(lt(5));
+        assert!(small.matches(&vec![1, 2]).is_match());
+        assert!(!small.matches(&vec![1, 7, 9]).is_match());
+        assert_eq!(
+            small.describe_mismatch(&vec![1, 7, 9]).as_deref(),
+            Some("[1] was 7\n[2] was 9")
+        );
+
+        let retries = has_field("retries", |c: &Config| &c.retries, eq(5));
+        assert!(!retries.matches(&config()).is_match());
+        assert_eq!(retries.describe(), "has field 'retries' equal to 5");
+        assert_eq!(
+            retries.describe_mismatch(&config()).as_deref(),
+            Some("field 'retries' was 3")
+        );
+
+        let hosts = has_field("hosts", |c: &Config| &c.hosts, contains("b"));
+        assert!(hosts.matches(&config()).is_match());
+    }
+}
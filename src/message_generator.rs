@@ -1,4 +1,4 @@
-use crate::context::AssertionContext;
+use crate::{context::AssertionContext, diff, error::AssertionError};
 
 use std::fmt::Debug;
 
@@ -6,32 +6,76 @@ pub(crate) fn expected_vs_actual_message<T: Debug, O: Debug>(
     actual: T,
     expected: O,
     context: AssertionContext<T>,
-) -> String {
+) -> AssertionError {
     let mapped_actual = (context.actual_mapper)(actual);
 
-    let custom_message = if let Some(msg) = context.custom_message {
-        format!("\n\nExtra details: {msg}")
-    } else {
-        "".to_string()
-    };
+    AssertionError {
+        verb: context.verb,
+        expected: Some(format!("{expected:?}")),
+        actual: strip_separator(mapped_actual),
+        details: None,
+        custom_message: context.custom_message,
+    }
+}
+
+/// Like 'expected_vs_actual_message', but with a line diff of the pretty
+/// 'Debug' representations when either of them spans multiple lines.
+pub(crate) fn expected_vs_actual_diff_message<T: Debug, O: Debug>(
+    actual: T,
+    expected: O,
+    context: AssertionContext<T>,
+) -> AssertionError {
+    let pretty_expected = format!("{expected:#?}");
+    let pretty_actual = format!("{actual:#?}");
+
+    let mut error = expected_vs_actual_message(actual, expected, context);
+    if diff::is_multiline(&pretty_expected, &pretty_actual) {
+        error.details = Some(diff::line_diff(&pretty_expected, &pretty_actual));
+    }
 
-    format!(
-        " {} {expected:?} but was{mapped_actual}{custom_message}",
-        context.verb
-    )
+    error
+}
+
+/// Like 'expected_vs_actual_message', but detailing where the generated
+/// sequences diverge.
+pub(crate) fn sequence_diff_message<I, O>(
+    actual: I,
+    expected: O,
+    context: AssertionContext<I>,
+) -> AssertionError
+where
+    I: Iterator<Item: PartialEq + Debug> + Clone + Debug,
+    O: IntoIterator<Item = I::Item> + Clone + Debug,
+{
+    let actual_items: Vec<_> = actual.clone().collect();
+    let expected_items: Vec<_> = expected.clone().into_iter().collect();
+
+    let mut error = expected_vs_actual_message(actual, expected, context);
+    error.details = Some(diff::sequence_diff(&expected_items, &actual_items));
+
+    error
 }
 
 pub(crate) fn failed_condition_message<T: Debug>(
     actual: T,
     context: AssertionContext<T>,
-) -> String {
+) -> AssertionError {
     let mapped_actual = (context.actual_mapper)(actual);
 
-    let custom_message = if let Some(msg) = context.custom_message {
-        format!("\n\nExtra details: {msg}")
-    } else {
-        "".to_string()
-    };
+    AssertionError {
+        verb: context.verb,
+        expected: None,
+        actual: strip_separator(mapped_actual),
+        details: None,
+        custom_message: context.custom_message,
+    }
+}
 
-    format!(" {} but was{mapped_actual}{custom_message}", context.verb)
+// Actual mappers produce text meant to directly follow "but was", so they lead
+// with a space.
+fn strip_separator(mapped_actual: String) -> String {
+    match mapped_actual.strip_prefix(' ') {
+        Some(stripped) => stripped.to_string(),
+        None => mapped_actual,
+    }
 }
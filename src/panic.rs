@@ -1,65 +1,148 @@
 use backtrace::{Backtrace, BacktraceFmt, BacktraceFrame, PrintFmt};
-use std::{any::Any, panic::PanicHookInfo};
+use std::{any::Any, borrow::Cow, cell::Cell, panic::PanicHookInfo};
 
-use crate::code_grabber;
+use crate::error::{AssertionFailure, SoftAssertionFailures};
+use crate::{code_grabber, diff};
+
+thread_local! {
+    // Whether panics on this thread are expected, and so should not be
+    // reported by the hook.
+    static SILENCED: Cell<bool> = const { Cell::new(false) };
+}
 
 pub(crate) fn test_hook(
     info: &PanicHookInfo<'_>,
-    default_hook: &Box<dyn Fn(&PanicHookInfo<'_>) + Sync + Send + 'static>,
+    default_hook: &(dyn Fn(&PanicHookInfo<'_>) + Sync + Send + 'static),
 ) {
     // https://github.com/rust-lang/rust/blob/4af7fa79a0e829c0edcc93434a8c788be8ec58c6/library/std/src/panicking.rs#L262-L263
 
+    if SILENCED.with(Cell::get) {
+        return;
+    }
+
+    // Panics raised by other code, including panics within closures passed
+    // to this library's assertions, are reported by the original hook.
+    let is_soft = info.payload().is::<SoftAssertionFailures>();
+    if !is_soft && !info.payload().is::<AssertionFailure>() {
+        return (default_hook)(info);
+    }
+
     let location = info.location().expect("panics must provide a location");
-    let assertion_message = payload_as_str(info.payload());
+    let mut assertion_message = payload_as_str(info.payload());
+    if diff::use_color() {
+        assertion_message = Cow::Owned(diff::colorize(&assertion_message));
+    }
 
     let thread = std::thread::current();
     let thread_name = thread.name().unwrap_or("<unnamed>");
 
-    let backtrace = Backtrace::new();
-
-    let frame_number = backtrace.frames().iter().position(|frame| {
-        frame.symbols().iter().any(|x| {
-            x.name()
-                .is_some_and(|name| name.to_string().contains("<T as should::extensions::"))
-        })
-    });
+    // Each soft failure already names its own location and source.
+    if is_soft {
+        eprintln!(
+            "Soft assertions failed on thread '{thread_name}' at {location}:\n{assertion_message}"
+        );
+        return;
+    }
 
-    match frame_number {
-        // If 'None' was returned, an assertion via this library did not cause
-        // the panic, so call the original panic hook.
+    match locate_assertion(&Backtrace::new()) {
+        // If 'None' was returned, the failure was raised from elsewhere, e.g.
+        // re-raised by a caller, so call the original panic hook.
         None => (default_hook)(info),
 
-        // If 'Some' was returned, this library caused the panic. Omit the stack
-        // frames that were created by this library's functions.
-        Some(mut frame_num) => {
-            frame_num += 1;
-            let backtrace_string = format!(
-                "Assertion failed:\n{:?}",
-                BacktraceSubset {
-                    frames: &backtrace.frames()[frame_num..]
-                }
+        // If 'Some' was returned, this library caused the panic.
+        Some(site) => {
+            let location_string = site.location.unwrap_or_else(|| location.to_string());
+            let code_snippet = site
+                .code_snippet
+                .unwrap_or_else(|e| format!("(could not get source: {e:?})"));
+
+            eprintln!(
+                "Assertion failed on thread '{thread_name}' at {location_string}:\n'{code_snippet}'{assertion_message}\n\n{}",
+                site.backtrace
             );
+        }
+    };
+}
 
-            let location_string = match between(&backtrace_string, "at ", "\n") {
-                Some(value) => value,
-                None => &location.to_string(),
-            };
+/// Run the given 'block', without reporting any panic it raises on this
+/// thread. Only takes effect once this crate's hook is installed.
+pub(crate) fn silenced<R>(block: impl FnOnce() -> R) -> R {
+    let previous = SILENCED.with(|silenced| silenced.replace(true));
+    let result = block();
+    SILENCED.with(|silenced| silenced.set(previous));
+    result
+}
 
-            let assertion_fn = {
-                let backing = format!(
-                    "{:?}",
-                    BacktraceSubset {
-                        frames: &backtrace.frames()[frame_num - 1..frame_num]
-                    }
-                );
-                let first_line = backing.split("\n").next().unwrap();
-                code_grabber::get_assertion_function(first_line)
-            };
-            let code_snippet = code_grabber::get_code_snippet(location_string, &assertion_fn);
+/// Where an assertion made via this library was called from.
+pub(crate) struct AssertionSite {
+    /// The `file:line:column` of the caller, if it could be determined.
+    pub location: Option<String>,
+    /// The source of the asserted expression, or why it could not be read.
+    pub code_snippet: Result<String, String>,
+    /// The backtrace starting at the caller.
+    pub backtrace: String,
+    /// The demangled name of the calling function, if it could be determined.
+    pub caller: Option<String>,
+}
+
+/// Finds the caller of this library's outermost assertion function in the
+/// given 'backtrace', or 'None' if no assertion function is on the stack.
+pub(crate) fn locate_assertion(backtrace: &Backtrace) -> Option<AssertionSite> {
+    let frames = backtrace.frames();
+
+    let mut frame_num = frames.iter().position(is_assertion_frame)?;
 
-            eprintln!("Assertion failed on thread '{thread_name}' at {location_string}:\n'{code_snippet}'{assertion_message}\n\n{backtrace_string}");
+    // Assertions may delegate to one another, so skip every adjacent frame
+    // created by this library's functions.
+    while frames.get(frame_num + 1).is_some_and(is_assertion_frame) {
+        frame_num += 1;
+    }
+    frame_num += 1;
+
+    let backtrace_string = format!(
+        "Assertion failed:\n{:?}",
+        BacktraceSubset {
+            frames: &frames[frame_num..]
         }
+    );
+
+    let location = between(&backtrace_string, "at ", "\n").map(str::to_string);
+
+    let caller = frames
+        .get(frame_num)
+        .and_then(|frame| frame.symbols().first()?.name().map(|name| name.to_string()));
+
+    let assertion_fn = {
+        let backing = format!(
+            "{:?}",
+            BacktraceSubset {
+                frames: &frames[frame_num - 1..frame_num]
+            }
+        );
+        let first_line = backing.split('\n').next().unwrap_or_default();
+        code_grabber::get_assertion_function(first_line)
+    };
+
+    let code_snippet = match &location {
+        Some(location) => code_grabber::get_code_snippet(location, &assertion_fn),
+        None => Err("unknown location".to_string()),
     };
+
+    Some(AssertionSite {
+        location,
+        code_snippet,
+        backtrace: backtrace_string,
+        caller,
+    })
+}
+
+fn is_assertion_frame(frame: &BacktraceFrame) -> bool {
+    frame.symbols().iter().any(|x| {
+        x.name().is_some_and(|name| {
+            let name = name.to_string();
+            name.contains("should::extensions::") && name.contains("::Should")
+        })
+    })
 }
 
 // Used to create a subset of a backtrace. This is useful when omitting frames
@@ -88,13 +171,17 @@ impl std::fmt::Debug for BacktraceSubset<'_> {
     }
 }
 
-fn payload_as_str(payload: &dyn Any) -> &str {
-    if let Some(&s) = payload.downcast_ref::<&'static str>() {
-        s
+pub(crate) fn payload_as_str(payload: &dyn Any) -> Cow<'_, str> {
+    if let Some(failure) = payload.downcast_ref::<AssertionFailure>() {
+        Cow::Owned(failure.to_string())
+    } else if let Some(failures) = payload.downcast_ref::<SoftAssertionFailures>() {
+        Cow::Owned(failures.to_string())
+    } else if let Some(&s) = payload.downcast_ref::<&'static str>() {
+        Cow::Borrowed(s)
     } else if let Some(s) = payload.downcast_ref::<String>() {
-        s.as_str()
+        Cow::Borrowed(s.as_str())
     } else {
-        "Box<dyn Any>"
+        Cow::Borrowed("Box<dyn Any>")
     }
 }
 
@@ -102,5 +189,35 @@ fn between<'a>(source: &'a str, start: &'a str, end: &'a str) -> Option<&'a str>
     let start_position = source.find(start)? + start.len();
     let source = &source[start_position..];
     let end_position = source.find(end)?;
-    return Some(&source[..end_position]);
+    Some(&source[..end_position])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn payload_as_str_formats_assertion_failures() {
+        let failure = AssertionFailure {
+            verb: "should be".to_string(),
+            expected: Some("2".to_string()),
+            actual: "1".to_string(),
+            details: None,
+            custom_message: None,
+            location: None,
+            asserted_expression: None,
+        };
+
+        assert_eq!(payload_as_str(&failure), " should be 2 but was 1");
+        let failures = SoftAssertionFailures {
+            failures: vec![failure],
+        };
+        assert_eq!(
+            payload_as_str(&failures),
+            "1 soft assertion failed:\n\n1) at <unknown location>:\n'(could not get source)' should be 2 but was 1"
+        );
+        assert_eq!(payload_as_str(&"static"), "static");
+        assert_eq!(payload_as_str(&"owned".to_string()), "owned");
+        assert_eq!(payload_as_str(&1), "Box<dyn Any>");
+    }
 }
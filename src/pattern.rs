@@ -0,0 +1,210 @@
+use std::ops::Range;
+
+use regex_automata::{hybrid::dfa::DFA, Anchored, Input};
+
+/// Translate the given shell-style 'glob' into an equivalent regex matching
+/// the whole string. Supports `*`, `?`, `[...]` and `[!...]` classes, and `\`
+/// to escape the next character.
+pub(crate) fn glob_to_regex(glob: &str) -> String {
+    let mut regex = String::from("(?s)^");
+    let mut chars = glob.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '*' => regex.push_str(".*"),
+            '?' => regex.push('.'),
+            '\\' => match chars.next() {
+                Some(escaped) => regex.push_str(&regex::escape(&escaped.to_string())),
+                None => regex.push_str(r"\\"),
+            },
+            '[' => {
+                let rest: String = chars.clone().collect();
+                // The first member may itself be ']', so the class can only
+                // close after it.
+                let negation = usize::from(rest.starts_with('!'));
+                let first_member = rest[negation..].chars().next().map(char::len_utf8);
+                let end = first_member.and_then(|length| {
+                    let members_from = negation + length;
+                    rest[members_from..].find(']').map(|end| members_from + end)
+                });
+                let class = match end {
+                    Some(end) => &rest[..end],
+                    // Empty or unclosed classes are taken literally.
+                    None => {
+                        regex.push_str(r"\[");
+                        continue;
+                    }
+                };
+                chars.nth(class.chars().count());
+
+                regex.push('[');
+                let members = match class.strip_prefix('!') {
+                    Some(negated) => {
+                        regex.push('^');
+                        negated
+                    }
+                    None => class,
+                };
+                for c in members.chars() {
+                    // '-' keeps its meaning as a range, and '&' and '~' form
+                    // set operations in regex classes when doubled.
+                    if matches!(c, '\\' | '[' | ']' | '^' | '&' | '~') {
+                        regex.push('\\');
+                    }
+                    regex.push(c);
+                }
+                regex.push(']');
+            }
+            c => regex.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+
+    regex.push('$');
+    regex
+}
+
+// Trying every start offset is quadratic for patterns like `.*x`, so the
+// search for a partial match gives up after this many DFA transitions and
+// reports the best one found so far.
+const MAX_PARTIAL_MATCH_STEPS: usize = 1_000_000;
+
+/// Find the longest part of 'haystack' which the given 'pattern' could still
+/// have matched from its start, i.e. where matching got the furthest before
+/// failing. Returns 'None' if the pattern could not get past any character.
+pub(crate) fn closest_partial_match(pattern: &str, haystack: &str) -> Option<Range<usize>> {
+    let dfa = DFA::new(pattern).ok()?;
+    let mut cache = dfa.create_cache();
+    let mut best: Option<Range<usize>> = None;
+    let mut steps = 0;
+
+    for (start, _) in haystack.char_indices() {
+        if steps >= MAX_PARTIAL_MATCH_STEPS {
+            break;
+        }
+
+        let input = Input::new(haystack).range(start..).anchored(Anchored::Yes);
+        let Ok(mut state) = dfa.start_state_forward(&mut cache, &input) else {
+            continue;
+        };
+
+        let mut end = start;
+        for (offset, &byte) in haystack.as_bytes()[start..].iter().enumerate() {
+            steps += 1;
+            match dfa.next_state(&mut cache, state, byte) {
+                Ok(next) if !next.is_dead() && !next.is_quit() => {
+                    state = next;
+                    end = start + offset + 1;
+                }
+                _ => break,
+            }
+        }
+
+        while !haystack.is_char_boundary(end) {
+            end -= 1;
+        }
+        if end > start && best.as_ref().is_none_or(|best| end - start > best.len()) {
+            best = Some(start..end);
+        }
+    }
+
+    best
+}
+
+/// Render the 'Debug' representation of 'haystack', underlining the given
+/// 'range' of it on the following line.
+pub(crate) fn highlight(haystack: &str, range: Range<usize>) -> String {
+    // Measure the escaped text, without the closing quote, to find columns.
+    let column = format!("{:?}", &haystack[..range.start]).chars().count() - 1;
+    let width = format!("{:?}", &haystack[range]).chars().count() - 2;
+
+    format!(
+        "{haystack:?}\n{}{}",
+        " ".repeat(column),
+        "^".repeat(width.max(1))
+    )
+}
+
+/// Describe why the given regex 'pattern' did not match 'haystack'.
+pub(crate) fn mismatch_details(pattern: &str, haystack: &str) -> String {
+    match closest_partial_match(pattern, haystack) {
+        Some(range) => format!(
+            "Closest partial match:\n{}",
+            indent(&highlight(haystack, range))
+        ),
+        None => "No part of the string matched the start of the pattern".to_string(),
+    }
+}
+
+/// Describe where a pattern unexpectedly matched 'haystack'.
+pub(crate) fn match_details(haystack: &str, range: Range<usize>) -> String {
+    format!("Matched here:\n{}", indent(&highlight(haystack, range)))
+}
+
+fn indent(text: &str) -> String {
+    text.lines()
+        .map(|line| format!("    {line}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn glob_matches(glob: &str, text: &str) -> bool {
+        regex::Regex::new(&glob_to_regex(glob))
+            .unwrap()
+            .is_match(text)
+    }
+
+    #[test]
+    fn test_glob_to_regex() {
+        assert!(glob_matches("*.rs", "src/lib.rs"));
+        assert!(!glob_matches("*.rs", "lib.rs.bak"));
+        assert!(glob_matches("file-?.txt", "file-1.txt"));
+        assert!(glob_matches("[ab]-[!0-9]", "b-x"));
+        assert!(!glob_matches("[ab]-[!0-9]", "b-5"));
+        assert!(glob_matches(r"\*literal\?", "*literal?"));
+        assert!(glob_matches("a[b", "a[b"));
+        assert!(glob_matches("(1+1)", "(1+1)"));
+        assert!(glob_matches("[é€]", "€"));
+        assert!(glob_matches("[a^&&]", "&"));
+        assert!(!glob_matches("[a^&&]", "b"));
+    }
+
+    #[test]
+    fn test_glob_class_starting_with_bracket() {
+        assert!(glob_matches("[]]", "]"));
+        assert!(glob_matches("[]a]", "a"));
+        assert!(glob_matches("[!]a]", "b"));
+        assert!(!glob_matches("[!]a]", "]"));
+        assert!(!glob_matches("[!]a]", "a"));
+        assert!(glob_matches("[]", "[]"));
+        assert!(glob_matches("[!]", "[!]"));
+    }
+
+    #[test]
+    fn test_closest_partial_match() {
+        assert_eq!(
+            closest_partial_match(r"order \d+ shipped", "see order 12a shipped"),
+            Some(4..12)
+        );
+        assert_eq!(closest_partial_match("xyz", "abc"), None);
+        assert_eq!(closest_partial_match("héllo!", "a héllo?"), Some(2..8));
+
+        // Matching from every start would take quadratic time here.
+        let haystack = "a".repeat(100_000);
+        assert_eq!(
+            closest_partial_match(".*x", &haystack),
+            Some(0..haystack.len())
+        );
+    }
+
+    #[test]
+    fn test_highlight() {
+        assert_eq!(
+            highlight("say \"hi\" now", 4..8),
+            "\"say \\\"hi\\\" now\"\n     ^^^^^^"
+        );
+    }
+}
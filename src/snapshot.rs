@@ -0,0 +1,577 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, Mutex};
+
+use backtrace::Backtrace;
+use regex::Regex;
+
+use crate::context::AssertionContextBuilder;
+use crate::error::AssertionError;
+use crate::panic::locate_assertion;
+use crate::{diff, message_generator};
+
+/// Setting this environment variable to anything but "" or "0" rewrites the
+/// snapshots instead of comparing against them.
+const UPDATE_VARIABLE: &str = "SHOULD_UPDATE_SNAPSHOTS";
+
+thread_local! {
+    // How many snapshots have been taken under each name on this thread. Each
+    // test runs on its own thread, so this counts the snapshots of one test.
+    static TAKEN: RefCell<HashMap<PathBuf, usize>> = RefCell::new(HashMap::new());
+
+    // A directory the snapshots of this thread are read from instead, with
+    // no snapshot updated, so that tests do not depend on or rewrite files in
+    // the source tree.
+    #[cfg(test)]
+    static TEST_DIRECTORY: RefCell<Option<PathBuf>> = const { RefCell::new(None) };
+}
+
+/// Compare the 'rendered' value against the snapshot of the assertion being
+/// made, or rewrite the snapshot if requested.
+pub(crate) fn check(rendered: &str) -> Result<(), AssertionError> {
+    match snapshot_path() {
+        Ok(path) => check_snapshot(rendered, &path, update_requested()),
+        Err(reason) => Err(snapshot_error(
+            "should match its snapshot",
+            format!("Could not locate the snapshot: {reason}"),
+        )),
+    }
+}
+
+/// Compare the 'rendered' value against the 'expected' inline snapshot, or
+/// rewrite the literal holding it in the calling source file if requested.
+pub(crate) fn check_inline(rendered: &str, expected: &str) -> Result<(), AssertionError> {
+    // Escaped literals hold the value as is, raw ones are laid out by
+    // 'inline_literal' and need to be dedented.
+    if expected == rendered {
+        return Ok(());
+    }
+    let expected = dedent(expected);
+    if expected == rendered {
+        return Ok(());
+    }
+
+    let verb = "should match its inline snapshot";
+    if !update_requested() {
+        return Err(snapshot_error(
+            verb,
+            format!(
+                "{}\n\nRun with {UPDATE_VARIABLE}=1 to accept the new value.",
+                diff::text_diff(&expected, rendered)
+            ),
+        ));
+    }
+
+    let location = locate_assertion(&Backtrace::new())
+        .and_then(|site| site.location)
+        .ok_or_else(|| snapshot_error(verb, "Could not locate the inline snapshot".to_string()))?;
+    update_inline(&location, rendered).map_err(|reason| {
+        snapshot_error(
+            verb,
+            format!("Could not update the inline snapshot: {reason}"),
+        )
+    })
+}
+
+/// The edits to inline snapshots made so far, for each source file.
+static INLINE_EDITS: LazyLock<Mutex<HashMap<PathBuf, InlineEdits>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+struct InlineEdits {
+    /// The file's contents before any edit, which the reported line numbers
+    /// refer to.
+    original: String,
+    /// The byte range of each rewritten literal in 'original', with its
+    /// replacement.
+    edits: Vec<(Range<usize>, String)>,
+}
+
+/// Rewrite the literal of the inline snapshot asserted at the given
+/// `file:line:column` 'location' to hold 'rendered'.
+///
+/// Line numbers only stay valid for the original source, so every edit to a
+/// file is batched, and the file is rewritten from its original contents with
+/// all edits applied bottom-up.
+fn update_inline(location: &str, rendered: &str) -> Result<(), String> {
+    let mut parts = location.rsplitn(3, ':');
+    let column: usize = parts
+        .next()
+        .and_then(|x| x.parse().ok())
+        .ok_or("bad column")?;
+    let line: usize = parts
+        .next()
+        .and_then(|x| x.parse().ok())
+        .ok_or("bad line")?;
+    let path = resolve_source(Path::new(parts.next().ok_or("bad file")?));
+
+    let mut all_edits = INLINE_EDITS.lock().unwrap_or_else(|e| e.into_inner());
+    let file = match all_edits.entry(path.clone()) {
+        std::collections::hash_map::Entry::Occupied(entry) => entry.into_mut(),
+        std::collections::hash_map::Entry::Vacant(entry) => entry.insert(InlineEdits {
+            original: std::fs::read_to_string(&path).map_err(|e| e.to_string())?,
+            edits: Vec::new(),
+        }),
+    };
+
+    let range = find_inline_literal(&file.original, line, column)?;
+    let indent = line_indent(&file.original, range.start);
+    let literal = inline_literal(rendered, indent);
+
+    file.edits.retain(|(existing, _)| *existing != range);
+    file.edits.push((range, literal));
+    file.edits
+        .sort_by_key(|(range, _)| std::cmp::Reverse(range.start));
+
+    let mut source = file.original.clone();
+    for (range, literal) in &file.edits {
+        source.replace_range(range.clone(), literal);
+    }
+    std::fs::write(&path, source).map_err(|e| e.to_string())
+}
+
+/// Find the byte range of the string literal passed to the inline snapshot
+/// assertion at the given 1-based 'line' and 'column' of 'source'.
+fn find_inline_literal(source: &str, line: usize, column: usize) -> Result<Range<usize>, String> {
+    static CALL: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"inline(_display)?_snapshot\s*\(\s*").unwrap());
+
+    let line_start = source
+        .split_inclusive('\n')
+        .take(line.saturating_sub(1))
+        .map(str::len)
+        .sum::<usize>();
+    if line_start >= source.len() && line > 1 {
+        return Err(format!("could not access line {line}"));
+    }
+    let mut start = (line_start + column.saturating_sub(1)).min(source.len());
+    while !source.is_char_boundary(start) {
+        start -= 1;
+    }
+
+    let call = CALL
+        .find_at(source, start)
+        .or_else(|| CALL.find_at(source, line_start))
+        .ok_or("could not find the inline snapshot call")?;
+    let literal_start = call.end();
+    let length = string_literal_len(&source[literal_start..])
+        .ok_or("the inline snapshot is not a string literal")?;
+
+    Ok(literal_start..literal_start + length)
+}
+
+/// The length of the (raw) string literal at the start of 'code', if any.
+fn string_literal_len(code: &str) -> Option<usize> {
+    if let Some(raw) = code.strip_prefix('r') {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        let body = raw[hashes..].strip_prefix('"')?;
+        let terminator = format!("\"{}", "#".repeat(hashes));
+        let end = body.find(&terminator)?;
+        return Some(1 + hashes + 1 + end + terminator.len());
+    }
+
+    let body = code.strip_prefix('"')?;
+    let mut escaped = false;
+    for (index, c) in body.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '"' => return Some(1 + index + 1),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// The indentation of the line containing the byte 'offset' of 'source'.
+fn line_indent(source: &str, offset: usize) -> &str {
+    let line_start = source[..offset].rfind('\n').map_or(0, |index| index + 1);
+    let line = &source[line_start..];
+    &line[..line.len() - line.trim_start().len()]
+}
+
+/// Write 'rendered' as a raw string literal. Multi-line values start on their
+/// own line, indented one level deeper than the given 'indent'. Values which
+/// a raw string cannot hold, or which 'dedent' would not read back unchanged,
+/// are written as an escaped string literal instead.
+fn inline_literal(rendered: &str, indent: &str) -> String {
+    let escaped = || format!("{rendered:?}");
+    if rendered.contains('\r') || rendered.contains("\"#") {
+        return escaped();
+    }
+
+    if !rendered.contains('\n') {
+        return format!("r#\"{rendered}\"#");
+    }
+
+    let body: Vec<String> = rendered
+        .split('\n')
+        .map(|line| {
+            if line.is_empty() {
+                String::new()
+            } else {
+                format!("{indent}    {line}")
+            }
+        })
+        .collect();
+    let body = format!("\n{}\n{indent}", body.join("\n"));
+    if dedent(&body) != rendered {
+        return escaped();
+    }
+    format!("r#\"{body}\"#")
+}
+
+/// Undo the layout of multi-line inline snapshots: drop the line break after
+/// the opening quote, the indentation common to all lines, and the final line
+/// if it only holds the closing quote's indentation.
+fn dedent(expected: &str) -> String {
+    let Some(body) = expected.strip_prefix('\n') else {
+        return expected.to_string();
+    };
+
+    let mut lines: Vec<&str> = body.split('\n').collect();
+    if lines.last().is_some_and(|line| line.trim().is_empty()) {
+        lines.pop();
+    }
+
+    let indent = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    lines
+        .iter()
+        .map(|line| line.get(indent..).unwrap_or(""))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn update_requested() -> bool {
+    #[cfg(test)]
+    if test_directory().is_some() {
+        return false;
+    }
+
+    std::env::var_os(UPDATE_VARIABLE).is_some_and(|value| !value.is_empty() && value != "0")
+}
+
+#[cfg(test)]
+fn test_directory() -> Option<PathBuf> {
+    TEST_DIRECTORY.with(|directory| directory.borrow().clone())
+}
+
+/// Run the given 'block' with snapshots read from 'directory' instead of the
+/// `snapshots` directory next to the test, and neither those nor inline
+/// snapshots updated.
+#[cfg(test)]
+pub(crate) fn isolated<R>(directory: &Path, block: impl FnOnce() -> R) -> R {
+    TEST_DIRECTORY.with(|active| *active.borrow_mut() = Some(directory.to_path_buf()));
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(block));
+    TEST_DIRECTORY.with(|active| *active.borrow_mut() = None);
+    result.unwrap_or_else(|payload| std::panic::resume_unwind(payload))
+}
+
+/// Find the source 'file' reported by a backtrace. Relative paths are
+/// relative to where the compiler ran, which is the workspace root rather
+/// than the crate being tested in multi-crate workspaces, so they are looked
+/// up from `CARGO_MANIFEST_DIR` upwards.
+pub(crate) fn resolve_source(file: &Path) -> PathBuf {
+    if file.is_absolute() {
+        return file.to_path_buf();
+    }
+
+    std::env::var_os("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .and_then(|manifest_dir| {
+            manifest_dir
+                .ancestors()
+                .map(|directory| directory.join(file))
+                .find(|candidate| candidate.is_file())
+        })
+        .unwrap_or_else(|| file.to_path_buf())
+}
+
+/// The snapshot for the assertion being made: in a `snapshots` directory next
+/// to the calling file, named after the file and the running test, and
+/// numbered from the second snapshot of the same test on.
+fn snapshot_path() -> Result<PathBuf, String> {
+    let site = locate_assertion(&Backtrace::new()).ok_or("no assertion on the stack")?;
+    let location = site.location.ok_or("unknown location")?;
+    let file = resolve_source(Path::new(location_file(&location)));
+
+    // The test harness names each test's thread after the test's full path,
+    // which unlike the calling function stays unique when tests share a
+    // helper or modules of one file reuse a test name.
+    let function = test_name()
+        .or_else(|| {
+            site.caller
+                .as_deref()
+                .and_then(function_name)
+                .map(str::to_string)
+        })
+        .ok_or("unknown calling function")?;
+    let stem = file
+        .file_stem()
+        .ok_or("unknown file name")?
+        .to_string_lossy();
+
+    let directory = file.with_file_name("snapshots");
+    #[cfg(test)]
+    let directory = test_directory().unwrap_or(directory);
+
+    let base = directory.join(format!("{stem}__{function}"));
+    let count = TAKEN.with(|taken| {
+        let mut taken = taken.borrow_mut();
+        let count = taken.entry(base.clone()).or_default();
+        *count += 1;
+        *count
+    });
+
+    let mut name = base.into_os_string();
+    if count > 1 {
+        name.push(format!("-{count}"));
+    }
+    name.push(".snap");
+    Ok(name.into())
+}
+
+/// The path of the test running on this thread, such as `a__renders` for the
+/// test `a::renders`, if the thread belongs to a test.
+fn test_name() -> Option<String> {
+    let thread = std::thread::current();
+    let name = thread.name().filter(|name| *name != "main")?;
+    Some(name.replace("::", "__"))
+}
+
+/// The file of a `file:line:column` location.
+fn location_file(location: &str) -> &str {
+    location.rsplitn(3, ':').nth(2).unwrap_or(location)
+}
+
+/// The name of the function in the given demangled 'symbol', skipping the
+/// closures and hash suffix around it.
+fn function_name(symbol: &str) -> Option<&str> {
+    symbol.rsplit("::").find(|segment| {
+        let is_hash = segment.len() == 17
+            && segment.starts_with('h')
+            && segment[1..].chars().all(|c| c.is_ascii_hexdigit());
+        !segment.is_empty() && !segment.starts_with('{') && !is_hash
+    })
+}
+
+fn check_snapshot(rendered: &str, path: &Path, update: bool) -> Result<(), AssertionError> {
+    let verb = format!("should match the snapshot at {}", path.display());
+
+    if update {
+        let written = path
+            .parent()
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|_| std::fs::write(path, format!("{rendered}\n")));
+        return written.map_err(|error| {
+            snapshot_error(&verb, format!("Could not write the snapshot: {error}"))
+        });
+    }
+
+    let stored = match std::fs::read_to_string(path) {
+        Ok(stored) => stored.replace("\r\n", "\n"),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+            return Err(snapshot_error(
+                &verb,
+                format!("No snapshot exists yet; run with {UPDATE_VARIABLE}=1 to create it"),
+            ));
+        }
+        Err(error) => {
+            return Err(snapshot_error(
+                &verb,
+                format!("Could not read the snapshot: {error}"),
+            ));
+        }
+    };
+    let stored = stored.strip_suffix('\n').unwrap_or(&stored);
+
+    if stored == rendered {
+        return Ok(());
+    }
+
+    Err(snapshot_error(
+        &verb,
+        format!(
+            "{}\n\nRun with {UPDATE_VARIABLE}=1 to accept the new value.",
+            diff::text_diff(stored, rendered)
+        ),
+    ))
+}
+
+fn snapshot_error(verb: &str, details: String) -> AssertionError {
+    let mut error = message_generator::failed_condition_message(
+        (),
+        AssertionContextBuilder::new()
+            .verb(verb)
+            .actual_mapper(Box::new(|_| "".to_string()))
+            .into(),
+    );
+    error.details = Some(details);
+    error
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_snapshot(name: &str) -> PathBuf {
+        let path = std::env::temp_dir()
+            .join(format!("should-snapshots-{}", std::process::id()))
+            .join(format!("{name}.snap"));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    fn remove_temp_snapshot(path: &Path) {
+        std::fs::remove_file(path).unwrap();
+        // Other tests may still be using the directory.
+        let _ = std::fs::remove_dir(path.parent().unwrap());
+    }
+
+    #[test]
+    fn test_check_snapshot() {
+        let path = temp_snapshot("check");
+
+        let error = check_snapshot("one\ntwo", &path, false).unwrap_err();
+        assert!(error.details.unwrap().starts_with("No snapshot exists yet"));
+
+        check_snapshot("one\ntwo", &path, true).unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "one\ntwo\n");
+        check_snapshot("one\ntwo", &path, false).unwrap();
+
+        let error = check_snapshot("one\nthree", &path, false).unwrap_err();
+        assert_eq!(
+            error.verb,
+            format!("should match the snapshot at {}", path.display())
+        );
+        assert!(error
+            .details
+            .unwrap()
+            .starts_with("Diff (-expected +actual):\n  one\n- two\n+ three"));
+
+        remove_temp_snapshot(&path);
+    }
+
+    #[test]
+    fn test_check_snapshot_ignores_crlf() {
+        let path = temp_snapshot("crlf");
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, "one\r\ntwo\r\n").unwrap();
+
+        check_snapshot("one\ntwo", &path, false).unwrap();
+
+        remove_temp_snapshot(&path);
+    }
+
+    #[test]
+    fn test_dedent() {
+        assert_eq!(dedent("single line"), "single line");
+        assert_eq!(
+            dedent("\n        Report {\n            a: 1,\n\n        }\n    "),
+            "Report {\n    a: 1,\n\n}"
+        );
+    }
+
+    #[test]
+    fn test_find_inline_literal() {
+        let source = "fn t() {\n    x.should_match_inline_snapshot(r#\"a\"b\"#);\n    y.should_match_inline_display_snapshot(\n        \"q\\\"\",\n    );\n}\n";
+
+        let range = find_inline_literal(source, 2, 5).unwrap();
+        assert_eq!(&source[range], "r#\"a\"b\"#");
+
+        let range = find_inline_literal(source, 3, 6).unwrap();
+        assert_eq!(&source[range], "\"q\\\"\"");
+    }
+
+    #[test]
+    fn test_inline_literal() {
+        assert_eq!(inline_literal("plain", "    "), "r#\"plain\"#");
+        assert_eq!(inline_literal("say \"#hi", ""), "\"say \\\"#hi\"");
+        assert_eq!(
+            inline_literal("A {\n\n}", "    "),
+            "r#\"\n        A {\n\n        }\n    \"#"
+        );
+        let literal = inline_literal("A {\n    b\n}", "  ");
+        assert_eq!(dedent(&literal[3..literal.len() - 2]), "A {\n    b\n}");
+    }
+
+    #[test]
+    fn test_inline_literal_keeps_common_indentation() {
+        let rendered = "  indented\n  text";
+        let literal = inline_literal(rendered, "    ");
+        assert_eq!(literal, "\"  indented\\n  text\"");
+        check_inline(rendered, "  indented\n  text").unwrap();
+    }
+
+    #[test]
+    fn test_inline_literal_escapes_carriage_returns() {
+        let rendered = "one\r\ntwo";
+        let literal = inline_literal(rendered, "    ");
+        assert_eq!(literal, "\"one\\r\\ntwo\"");
+        check_inline(rendered, "one\r\ntwo").unwrap();
+
+        assert_eq!(inline_literal("a\rb", ""), "\"a\\rb\"");
+    }
+
+    #[test]
+    fn test_update_inline_batches_edits() {
+        let path = temp_snapshot("inline").with_extension("rs");
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        let original = "fn t() {\n    a.should_match_inline_snapshot(\"\");\n    b.should_match_inline_snapshot(\"\");\n}\n";
+        std::fs::write(&path, original).unwrap();
+        let location = |line: usize| format!("{}:{line}:5", path.display());
+
+        // Grow the first literal by several lines, then edit the second one
+        // using its line number in the original source.
+        update_inline(&location(2), "one\ntwo").unwrap();
+        update_inline(&location(3), "three").unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "fn t() {\n    a.should_match_inline_snapshot(r#\"\n        one\n        two\n    \"#);\n    b.should_match_inline_snapshot(r#\"three\"#);\n}\n"
+        );
+
+        remove_temp_snapshot(&path);
+    }
+
+    #[test]
+    fn test_function_name() {
+        assert_eq!(
+            function_name("my_crate::tests::renders_report::{{closure}}::h0123456789abcdef"),
+            Some("renders_report")
+        );
+        assert_eq!(
+            function_name("tests::renders_report"),
+            Some("renders_report")
+        );
+        assert_eq!(location_file("/src/lib.rs:10:5"), "/src/lib.rs");
+    }
+
+    #[test]
+    fn test_resolve_source() {
+        let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+
+        assert_eq!(
+            resolve_source(Path::new("src/snapshot.rs")),
+            manifest_dir.join("src/snapshot.rs")
+        );
+
+        // Paths relative to a workspace root above the crate are found too.
+        let crate_name = manifest_dir.file_name().unwrap();
+        assert_eq!(
+            resolve_source(&Path::new(crate_name).join("src/snapshot.rs")),
+            manifest_dir.join("src/snapshot.rs")
+        );
+        assert_eq!(
+            resolve_source(Path::new("/absolute/lib.rs")),
+            PathBuf::from("/absolute/lib.rs")
+        );
+    }
+}
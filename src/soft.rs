@@ -0,0 +1,172 @@
+use std::cell::RefCell;
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+
+use crate::assertions::install_hook;
+use crate::diff;
+use crate::error::{AssertionFailure, SoftAssertionFailures};
+
+thread_local! {
+    // The failures collected by the active soft scope on this thread, if any.
+    static COLLECTOR: RefCell<Option<Vec<AssertionFailure>>> = const { RefCell::new(None) };
+}
+
+/// Run the given 'block' with soft assertions: instead of panicking on the
+/// first failure, every failed assertion is recorded and a single panic
+/// listing all of them is raised once 'block' finishes, with a
+/// 'SoftAssertionFailures' payload.
+///
+/// Only assertions made on the current thread are collected. Nested calls
+/// report their failures to the outermost scope.
+#[track_caller]
+pub fn soft<R>(block: impl FnOnce() -> R) -> R {
+    if is_active() {
+        return block();
+    }
+
+    COLLECTOR.with(|collector| *collector.borrow_mut() = Some(Vec::new()));
+    let result = catch_unwind(AssertUnwindSafe(block));
+    let failures = SoftAssertionFailures {
+        failures: COLLECTOR
+            .with(|collector| collector.borrow_mut().take())
+            .unwrap_or_default(),
+    };
+
+    match result {
+        Ok(value) if failures.failures.is_empty() => value,
+        Ok(_) => {
+            install_hook();
+            std::panic::panic_any(failures)
+        }
+        Err(payload) => {
+            // Something else panicked mid-scope; still surface what was
+            // collected before propagating the original panic.
+            if !failures.failures.is_empty() {
+                let summary = failures.to_string();
+                if diff::use_color() {
+                    eprintln!("{}", diff::colorize(&summary));
+                } else {
+                    eprintln!("{summary}");
+                }
+            }
+            resume_unwind(payload)
+        }
+    }
+}
+
+fn is_active() -> bool {
+    COLLECTOR.with(|collector| collector.borrow().is_some())
+}
+
+/// Run the given 'block' outside of any active soft scope, so that failed
+/// assertions within it panic. The scope is restored afterwards, so 'block'
+/// must not unwind.
+pub(crate) fn suspended<R>(block: impl FnOnce() -> R) -> R {
+    let collector = COLLECTOR.with(|collector| collector.borrow_mut().take());
+    let result = block();
+    COLLECTOR.with(|active| *active.borrow_mut() = collector);
+    result
+}
+
+/// Record the given 'failure' if a soft scope is active on this thread.
+/// Returns whether the failure was recorded.
+pub(crate) fn record(failure: &AssertionFailure) -> bool {
+    if !is_active() {
+        return false;
+    }
+
+    COLLECTOR.with(|collector| {
+        if let Some(failures) = collector.borrow_mut().as_mut() {
+            failures.push(failure.clone());
+        }
+    });
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ShouldBeEqExtension, ShouldBeStringExtension};
+
+    fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+        payload
+            .downcast::<SoftAssertionFailures>()
+            .map(|failures| failures.to_string())
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn passing_scope_returns_value() {
+        let value = soft(|| {
+            1.should_be(&1);
+            "hello".should_contain("ell");
+            5
+        });
+
+        value.should_be(&5);
+    }
+
+    #[test]
+    fn collects_every_failure() {
+        let result = std::panic::catch_unwind(|| {
+            soft(|| {
+                1.should_be(&2);
+                "hello".should_contain("world");
+                3.should_be(&3);
+            })
+        });
+
+        let message = panic_message(result.unwrap_err());
+        assert!(message.starts_with("2 soft assertions failed:"));
+        assert!(message.contains("1) at "));
+        assert!(message.contains(" should be 2 but was 1"));
+        assert!(message.contains("2) at "));
+        assert!(message.contains(" should contain \"world\" but was \"hello\""));
+        assert!(!message.contains("3)"));
+    }
+
+    #[test]
+    fn panics_with_the_collected_failures() {
+        let payload = std::panic::catch_unwind(|| {
+            soft(|| {
+                1.should_be(&2);
+                "hello".should_contain("world");
+            })
+        })
+        .unwrap_err();
+
+        let failures = payload
+            .downcast_ref::<SoftAssertionFailures>()
+            .expect("payload should be SoftAssertionFailures");
+        let verbs: Vec<&str> = failures.failures.iter().map(|f| f.verb.as_str()).collect();
+        assert_eq!(verbs, ["should be", "should contain"]);
+        assert_eq!(
+            failures.failures[0].asserted_expression.as_deref(),
+            Some("1")
+        );
+        assert!(crate::panic::payload_as_str(&*payload).starts_with("2 soft assertions failed:"));
+    }
+
+    #[test]
+    fn nested_scopes_report_to_outermost() {
+        let result = std::panic::catch_unwind(|| {
+            soft(|| {
+                1.should_be(&2);
+                soft(|| 2.should_be(&3));
+            })
+        });
+
+        let message = panic_message(result.unwrap_err());
+        assert!(message.starts_with("2 soft assertions failed:"));
+    }
+
+    #[test]
+    fn scope_is_reset_after_failure() {
+        let result = std::panic::catch_unwind(|| soft(|| 1.should_be(&2)));
+        assert!(result.is_err());
+
+        assert!(!is_active());
+        let result = std::panic::catch_unwind(|| 1.should_be(&2));
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,249 @@
+use crate::config;
+use crate::extensions::json::JsonOptions;
+
+use std::collections::HashMap;
+use std::fmt::{Debug, Display, Formatter};
+
+/// A structured value as compared by the JSON and serde assertions. Unlike
+/// JSON, numbers keep their full 128-bit range and non-finite floats, and map
+/// keys may be any value.
+#[derive(Clone, PartialEq)]
+pub(crate) enum Tree {
+    Null,
+    Bool(bool),
+    Number(Number),
+    String(String),
+    Array(Vec<Tree>),
+    Map(Vec<(Tree, Tree)>),
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum Number {
+    /// Any non-negative integer.
+    Unsigned(u128),
+    /// A negative integer.
+    Negative(i128),
+    Float(f64),
+}
+
+impl Number {
+    pub(crate) fn signed(value: i128) -> Self {
+        match u128::try_from(value) {
+            Ok(value) => Number::Unsigned(value),
+            Err(_) => Number::Negative(value),
+        }
+    }
+
+    fn is_float(&self) -> bool {
+        matches!(self, Number::Float(_))
+    }
+
+    fn as_f64(&self) -> f64 {
+        match *self {
+            Number::Unsigned(value) => value as f64,
+            Number::Negative(value) => value as f64,
+            Number::Float(value) => value,
+        }
+    }
+}
+
+impl From<&serde_json::Value> for Tree {
+    fn from(value: &serde_json::Value) -> Self {
+        use serde_json::Value;
+
+        match value {
+            Value::Null => Tree::Null,
+            Value::Bool(value) => Tree::Bool(*value),
+            Value::Number(number) => Tree::Number(match (number.as_u64(), number.as_i64()) {
+                (Some(value), _) => Number::Unsigned(value.into()),
+                (None, Some(value)) => Number::signed(value.into()),
+                (None, None) => Number::Float(number.as_f64().unwrap_or(f64::NAN)),
+            }),
+            Value::String(value) => Tree::String(value.clone()),
+            Value::Array(items) => Tree::Array(items.iter().map(Tree::from).collect()),
+            Value::Object(fields) => Tree::Map(
+                fields
+                    .iter()
+                    .map(|(key, value)| (Tree::String(key.clone()), Tree::from(value)))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// Formats as compact JSON, with non-string map keys written as they are.
+impl Display for Tree {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tree::Null => write!(f, "null"),
+            Tree::Bool(value) => write!(f, "{value}"),
+            Tree::Number(Number::Unsigned(value)) => write!(f, "{value}"),
+            Tree::Number(Number::Negative(value)) => write!(f, "{value}"),
+            Tree::Number(Number::Float(value)) => write!(f, "{value:?}"),
+            Tree::String(value) => write!(f, "{}", serde_json::Value::from(value.as_str())),
+            Tree::Array(items) => {
+                write!(f, "[")?;
+                for (index, item) in items.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                write!(f, "]")
+            }
+            Tree::Map(entries) => {
+                write!(f, "{{")?;
+                for (index, (key, value)) in entries.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{key}:{value}")?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+impl Debug for Tree {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+/// Collect a description of each difference between 'expected' and 'actual',
+/// which are found at the given display 'path' and JSON 'pointer'.
+pub(crate) fn compare(
+    expected: &Tree,
+    actual: &Tree,
+    path: &str,
+    pointer: &str,
+    options: &JsonOptions,
+    mismatches: &mut Vec<String>,
+) {
+    if options.ignored.iter().any(|ignored| ignored == pointer) {
+        return;
+    }
+
+    match (expected, actual) {
+        (Tree::Map(expected), Tree::Map(actual)) => {
+            let actual_by_key = index_by_key(actual);
+            for (key, expected_value) in expected {
+                let (path, pointer) = (key_path(path, key), key_pointer(pointer, key));
+                match actual_by_key.get(&key.to_string()) {
+                    Some(actual_value) => compare(
+                        expected_value,
+                        actual_value,
+                        &path,
+                        &pointer,
+                        options,
+                        mismatches,
+                    ),
+                    None if options.ignored.contains(&pointer) => {}
+                    None => {
+                        mismatches.push(format!("{path}: expected {expected_value}, was missing"))
+                    }
+                }
+            }
+
+            if options.subset {
+                return;
+            }
+            let expected_by_key = index_by_key(expected);
+            for (key, actual_value) in actual {
+                let pointer = key_pointer(pointer, key);
+                if !expected_by_key.contains_key(&key.to_string())
+                    && !options.ignored.contains(&pointer)
+                {
+                    mismatches.push(format!(
+                        "{}: expected nothing, was {actual_value}",
+                        key_path(path, key)
+                    ));
+                }
+            }
+        }
+        (Tree::Array(expected), Tree::Array(actual)) => {
+            for index in 0..expected.len().max(actual.len()) {
+                let path = format!("{path}[{index}]");
+                let pointer = format!("{pointer}/{index}");
+                match (expected.get(index), actual.get(index)) {
+                    (Some(expected), Some(actual)) => {
+                        compare(expected, actual, &path, &pointer, options, mismatches)
+                    }
+                    _ if options.ignored.contains(&pointer) => {}
+                    (Some(expected), None) => {
+                        mismatches.push(format!("{path}: expected {expected}, was missing"))
+                    }
+                    (None, Some(actual)) => {
+                        mismatches.push(format!("{path}: expected nothing, was {actual}"))
+                    }
+                    (None, None) => {}
+                }
+            }
+        }
+        // Treat numbers as equal when they have the same value, e.g. 10 and 10.0.
+        // Integers are compared exactly, as converting large ones to f64 would
+        // round them.
+        (Tree::Number(x), Tree::Number(y))
+            if x == y || ((x.is_float() || y.is_float()) && x.as_f64() == y.as_f64()) => {}
+        _ if expected == actual => {}
+        _ => {
+            // Values compared from the root without a marker, as serde values
+            // are, still need a path to show.
+            let path = if path.is_empty() { "." } else { path };
+            mismatches.push(format!("{path}: expected {expected}, was {actual}"))
+        }
+    }
+}
+
+/// List the given 'mismatches', up to the configured maximum.
+pub(crate) fn describe_mismatches(mismatches: &[String]) -> String {
+    let limit = config::max_reported_elements();
+    let mut details = format!("{} mismatch(es):", mismatches.len());
+    for mismatch in mismatches.iter().take(limit) {
+        details.push_str(&format!("\n    {mismatch}"));
+    }
+    if mismatches.len() > limit {
+        details.push_str(&format!("\n    ... and {} more", mismatches.len() - limit));
+    }
+    details
+}
+
+/// Look up the values of a map by their key's compact form, which is unique
+/// per key.
+fn index_by_key(entries: &[(Tree, Tree)]) -> HashMap<String, &Tree> {
+    entries
+        .iter()
+        .map(|(key, value)| (key.to_string(), value))
+        .collect()
+}
+
+/// Extend a display path with a map key, using dot notation where the key is
+/// a plain identifier.
+fn key_path(path: &str, key: &Tree) -> String {
+    let Tree::String(key) = key else {
+        return format!("{path}[{key}]");
+    };
+
+    let is_identifier = key
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+    if is_identifier {
+        format!("{path}.{key}")
+    } else {
+        format!("{path}[{}]", serde_json::Value::from(key.as_str()))
+    }
+}
+
+/// Extend a JSON pointer with a map key. Keys which are not strings are
+/// written in their compact form.
+fn key_pointer(pointer: &str, key: &Tree) -> String {
+    let key = match key {
+        Tree::String(key) => key.clone(),
+        key => key.to_string(),
+    };
+    format!("{pointer}/{}", key.replace('~', "~0").replace('/', "~1"))
+}
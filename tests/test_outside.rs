@@ -34,6 +34,7 @@ fn example2() {
 }
 
 #[test]
+#[should_panic]
 fn basic_test() {
     let x = 0;
     let y = 10;
@@ -45,7 +46,7 @@ fn basic_test() {
     let my_str = "Hello, world!";
     my_str.should_contain("world");
 
-    let my_vec = vec![1, 3, 2];
+    let my_vec = [1, 3, 2];
     my_vec.iter().should_be_size(my_vec.len());
     my_vec.iter().should_contain(&&3);
 